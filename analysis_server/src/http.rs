@@ -0,0 +1,151 @@
+use std::io::{self, BufRead, Read, Write};
+
+use crate::json::Json;
+
+const MAX_REQUEST_BYTES: u64 = 8192;
+const MAX_HEADERS: usize = 64;
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub query: Vec<(String, String)>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: Json,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct InvalidRequest;
+
+impl Request {
+    pub fn read<R: BufRead>(reader: &mut R) -> io::Result<Result<Request, InvalidRequest>> {
+        let mut reader = reader.by_ref().take(MAX_REQUEST_BYTES);
+
+        let mut request_line = String::new();
+
+        if !read_line(&mut reader, &mut request_line)? {
+            return Ok(Err(InvalidRequest));
+        }
+
+        for _ in 0..=MAX_HEADERS {
+            let mut header = String::new();
+
+            if !read_line(&mut reader, &mut header)? {
+                return Ok(Err(InvalidRequest));
+            } else if header.trim().is_empty() {
+                return Ok(Request::parse(&request_line));
+            }
+        }
+
+        Ok(Err(InvalidRequest))
+    }
+
+    pub fn parse(request_line: &str) -> Result<Request, InvalidRequest> {
+        let words: Vec<&str> = request_line.split_whitespace().collect();
+
+        let (method, target) = match words.as_slice() {
+            [method, target, version] if version.starts_with("HTTP/") => (method, target),
+            _ => return Err(InvalidRequest),
+        };
+
+        let mut target = target.splitn(2, '?');
+        let path = decode(target.next().unwrap_or(""))?;
+
+        let query = match target.next() {
+            Some(query) => query
+                .split('&')
+                .filter(|pair| !pair.is_empty())
+                .map(|pair| {
+                    let mut pair = pair.splitn(2, '=');
+                    let key = decode(pair.next().unwrap_or(""))?;
+                    let value = decode(pair.next().unwrap_or(""))?;
+                    Ok((key, value))
+                })
+                .collect::<Result<_, _>>()?,
+            None => Vec::new(),
+        };
+
+        Ok(Request {
+            method: method.to_string(),
+            path,
+            query,
+        })
+    }
+
+    pub fn get_parameter(&self, name: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(key, _value)| key == name)
+            .map(|(_key, value)| value.as_str())
+    }
+}
+
+fn read_line<R: BufRead>(reader: &mut R, line: &mut String) -> io::Result<bool> {
+    reader.read_line(line)?;
+    Ok(line.ends_with('\n'))
+}
+
+fn decode(input: &str) -> Result<String, InvalidRequest> {
+    let mut bytes = Vec::new();
+    let mut iterator = input.bytes();
+
+    while let Some(byte) = iterator.next() {
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let high = iterator.next().and_then(|b| (b as char).to_digit(16));
+                let low = iterator.next().and_then(|b| (b as char).to_digit(16));
+
+                match (high, low) {
+                    (Some(high), Some(low)) => bytes.push((high * 16 + low) as u8),
+                    _ => return Err(InvalidRequest),
+                }
+            }
+            byte => bytes.push(byte),
+        }
+    }
+
+    String::from_utf8(bytes).map_err(|_| InvalidRequest)
+}
+
+impl Response {
+    pub fn ok(body: Json) -> Response {
+        Response { status: 200, body }
+    }
+
+    pub fn error(status: u16, message: &str) -> Response {
+        Response {
+            status,
+            body: Json::object(vec![("error", Json::string(message))]),
+        }
+    }
+
+    fn get_reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            422 => "Unprocessable Entity",
+            503 => "Service Unavailable",
+            _ => "Internal Server Error",
+        }
+    }
+
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let body = self.body.to_string();
+
+        write!(writer, "HTTP/1.1 {} {}\r\n", self.status, self.get_reason())?;
+        write!(writer, "Content-Type: application/json\r\n")?;
+        write!(writer, "Content-Length: {}\r\n", body.len())?;
+        write!(writer, "Access-Control-Allow-Origin: *\r\n")?;
+        write!(writer, "Connection: close\r\n")?;
+        write!(writer, "\r\n")?;
+        write!(writer, "{}", body)?;
+        writer.flush()
+    }
+}
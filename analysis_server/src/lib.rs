@@ -0,0 +1,281 @@
+mod http;
+mod json;
+
+#[cfg(test)]
+mod test;
+
+use std::io::{self, BufReader};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use ::minimax::engine::{iterative_deepening, SearchLimits};
+use ::minimax::notation::Notation;
+use ::minimax::GameResult::*;
+use ::minimax::Outcome::*;
+use ::minimax::{GameState, MinimaxError, Outcome, Player};
+
+use game_2048::Bitboard2048;
+use tic_tac_toe::TicTacToe;
+
+pub use crate::http::{InvalidRequest, Request, Response};
+pub use crate::json::Json;
+
+const TIC_TAC_TOE_DEFAULT_DEPTH: usize = 9;
+const TIC_TAC_TOE_MAX_DEPTH: usize = 9;
+const GAME_2048_DEFAULT_DEPTH: usize = 4;
+const GAME_2048_MAX_DEPTH: usize = 6;
+const DEFAULT_MOVETIME: Duration = Duration::from_secs(10);
+const MAX_MOVETIME: Duration = Duration::from_secs(60);
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_CONNECTIONS: usize = 16;
+
+pub struct Server {
+    listener: TcpListener,
+    connections: Arc<AtomicUsize>,
+}
+
+struct ConnectionSlot {
+    connections: Arc<AtomicUsize>,
+}
+
+impl ConnectionSlot {
+    fn acquire(connections: &Arc<AtomicUsize>) -> Option<ConnectionSlot> {
+        connections
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |count| {
+                Some(count + 1).filter(|count| *count <= MAX_CONNECTIONS)
+            })
+            .ok()
+            .map(|_count| ConnectionSlot {
+                connections: Arc::clone(connections),
+            })
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.connections.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+impl Server {
+    pub fn bind<A: ToSocketAddrs>(address: A) -> io::Result<Server> {
+        Ok(Server {
+            listener: TcpListener::bind(address)?,
+            connections: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    pub fn run<F>(&self, on_error: F)
+    where
+        F: Fn(io::Error) + Send + Sync + 'static,
+    {
+        let on_error = Arc::new(on_error);
+
+        for stream in self.listener.incoming() {
+            let stream = match stream {
+                Ok(stream) => stream,
+                Err(error) => {
+                    on_error(error);
+                    continue;
+                }
+            };
+
+            let slot = match ConnectionSlot::acquire(&self.connections) {
+                Some(slot) => slot,
+                None => {
+                    if let Err(error) = reject_connection(stream) {
+                        on_error(error);
+                    }
+
+                    continue;
+                }
+            };
+
+            let on_error = Arc::clone(&on_error);
+
+            thread::spawn(move || {
+                if let Err(error) = handle_connection(stream) {
+                    on_error(error);
+                }
+
+                drop(slot);
+            });
+        }
+    }
+}
+
+fn reject_connection(stream: TcpStream) -> io::Result<()> {
+    stream.set_write_timeout(Some(CONNECTION_TIMEOUT))?;
+    Response::error(503, "Too many connections.").write(&mut &stream)
+}
+
+pub fn handle_connection(stream: TcpStream) -> io::Result<()> {
+    stream.set_read_timeout(Some(CONNECTION_TIMEOUT))?;
+    stream.set_write_timeout(Some(CONNECTION_TIMEOUT))?;
+
+    let mut reader = BufReader::new(&stream);
+
+    let response = match Request::read(&mut reader)? {
+        Ok(request) => handle_request(&request),
+        Err(InvalidRequest) => Response::error(400, "Malformed request."),
+    };
+
+    response.write(&mut &stream)
+}
+
+pub fn handle_request(request: &Request) -> Response {
+    if request.path != "/analyze" {
+        return Response::error(404, "Unknown path.");
+    } else if request.method != "GET" {
+        return Response::error(405, "Only GET requests are allowed.");
+    }
+
+    let game = match request.get_parameter("game") {
+        Some(game) => game,
+        None => return Response::error(400, "Missing game parameter."),
+    };
+
+    let state = match request.get_parameter("state") {
+        Some(state) => state,
+        None => return Response::error(400, "Missing state parameter."),
+    };
+
+    let depth = match request.get_parameter("depth").map(str::parse) {
+        Some(Ok(depth)) => Some(depth),
+        Some(Err(_)) => return Response::error(400, "Invalid depth parameter."),
+        None => None,
+    };
+
+    let movetime = match request.get_parameter("movetime").map(str::parse) {
+        Some(Ok(movetime)) => Some(Duration::from_millis(movetime)),
+        Some(Err(_)) => return Response::error(400, "Invalid movetime parameter."),
+        None => None,
+    };
+
+    if movetime.is_some_and(|movetime| movetime > MAX_MOVETIME) {
+        return Response::error(
+            400,
+            &format!(
+                "The movetime must be at most {} milliseconds.",
+                MAX_MOVETIME.as_millis()
+            ),
+        );
+    }
+
+    let limits = SearchLimits { depth, movetime };
+
+    match game {
+        "tic_tac_toe" => analyze_state::<TicTacToe>(
+            game,
+            state,
+            limits,
+            TIC_TAC_TOE_DEFAULT_DEPTH,
+            TIC_TAC_TOE_MAX_DEPTH,
+        ),
+        "game_2048" => analyze_state::<Bitboard2048>(
+            game,
+            state,
+            limits,
+            GAME_2048_DEFAULT_DEPTH,
+            GAME_2048_MAX_DEPTH,
+        ),
+        _ => Response::error(400, "Unknown game."),
+    }
+}
+
+fn analyze_state<S>(
+    game: &str,
+    state: &str,
+    limits: SearchLimits,
+    default_depth: usize,
+    max_depth: usize,
+) -> Response
+where
+    S: GameState + Notation,
+    S::Move: Notation,
+{
+    let state = match S::parse(state) {
+        Ok(state) => state,
+        Err(_) => return Response::error(400, "Invalid state."),
+    };
+
+    if limits.depth.is_some_and(|depth| depth > max_depth) {
+        return Response::error(400, &format!("The depth must be at most {}.", max_depth));
+    }
+
+    let max_depth = match limits.movetime {
+        Some(_) => max_depth,
+        None => default_depth,
+    };
+
+    let limits = SearchLimits {
+        movetime: limits.movetime.or(Some(DEFAULT_MOVETIME)),
+        ..limits
+    };
+
+    let stop = Arc::new(AtomicBool::new(false));
+
+    let info = match iterative_deepening(&state, limits, max_depth, stop, &mut |_info| {}) {
+        Ok(info) => info,
+        Err(MinimaxError::GameAlreadyFinished) | Err(MinimaxError::NoPossibleMoves) => {
+            return Response::error(422, "The game is already finished.")
+        }
+        Err(_) => return Response::error(500, "The search failed."),
+    };
+
+    let (outcome, best_moves, statistics) = match info {
+        Some(info) => (
+            outcome_to_json(&info.outcome),
+            info.moves
+                .iter()
+                .map(|mov| Json::String(mov.format()))
+                .collect(),
+            Json::object(vec![
+                ("depth", Json::Number(info.depth as i64)),
+                ("nodes", Json::Number(info.nodes as i64)),
+                ("time_ms", Json::Number(info.time.as_millis() as i64)),
+            ]),
+        ),
+        None => (Json::Null, Vec::new(), Json::Null),
+    };
+
+    Response::ok(Json::object(vec![
+        ("game", Json::string(game)),
+        ("state", Json::String(state.format())),
+        ("outcome", outcome),
+        ("best_moves", Json::Array(best_moves)),
+        ("statistics", statistics),
+    ]))
+}
+
+fn player_to_json(player: Player) -> Json {
+    match player {
+        Player::One => Json::string("one"),
+        Player::Two => Json::string("two"),
+    }
+}
+
+fn outcome_to_json(outcome: &Outcome) -> Json {
+    match *outcome {
+        Definite(Win(player), moves) => Json::object(vec![
+            ("result", Json::string("win")),
+            ("winner", player_to_json(player)),
+            ("moves", Json::Number(moves as i64)),
+        ]),
+        Definite(Draw, moves) => Json::object(vec![
+            ("result", Json::string("draw")),
+            ("moves", Json::Number(moves as i64)),
+        ]),
+        Indefinite(score) => Json::object(vec![
+            ("result", Json::string("unknown")),
+            ("score", Json::Number(score as i64)),
+        ]),
+    }
+}
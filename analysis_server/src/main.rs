@@ -0,0 +1,20 @@
+use std::env;
+
+use analysis_server::Server;
+
+const DEFAULT_ADDRESS: &str = "127.0.0.1:8048";
+
+fn main() {
+    let address = env::args()
+        .nth(1)
+        .unwrap_or_else(|| DEFAULT_ADDRESS.to_string());
+
+    let server = Server::bind(&address).expect("It was not possible to bind the server address.");
+
+    match server.local_addr() {
+        Ok(address) => println!("Listening on http://{}/analyze", address),
+        Err(_) => println!("Listening on http://{}/analyze", address),
+    }
+
+    server.run(|error| eprintln!("Connection error: {}", error));
+}
@@ -0,0 +1,243 @@
+use crate::*;
+
+use std::io::{Read, Write};
+use std::thread;
+
+fn get(address: SocketAddr, target: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(address).unwrap();
+
+    write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", target).unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+
+    assert!(head.contains("Content-Type: application/json\r\n"));
+    assert!(head.contains(&format!("Content-Length: {}\r\n", body.len())));
+
+    let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+    (status, body.to_string())
+}
+
+fn without_time(body: &str) -> String {
+    match body.find(",\"time_ms\":") {
+        Some(start) => {
+            let end = start + body[start..].find('}').unwrap();
+            format!("{}{}", &body[..start], &body[end..])
+        }
+        None => body.to_string(),
+    }
+}
+
+#[test]
+fn test_json() {
+    let json = Json::object(vec![
+        ("null", Json::Null),
+        ("bool", Json::Bool(true)),
+        ("number", Json::Number(-12)),
+        ("string", Json::string("a \"quoted\"\\path\n\u{1}")),
+        (
+            "array",
+            Json::Array(vec![Json::Number(1), Json::Array(Vec::new())]),
+        ),
+        ("object", Json::object(Vec::new())),
+    ]);
+
+    assert_eq!(
+        json.to_string(),
+        "{\"null\":null,\"bool\":true,\"number\":-12,\
+         \"string\":\"a \\\"quoted\\\"\\\\path\\n\\u0001\",\
+         \"array\":[1,[]],\"object\":{}}"
+    );
+}
+
+#[test]
+fn test_request() {
+    let request = Request::parse("GET /analyze?game=game_2048&state=2%2C0+4&depth=3 HTTP/1.1\r\n");
+
+    assert_eq!(
+        request,
+        Ok(Request {
+            method: "GET".to_string(),
+            path: "/analyze".to_string(),
+            query: vec![
+                ("game".to_string(), "game_2048".to_string()),
+                ("state".to_string(), "2,0 4".to_string()),
+                ("depth".to_string(), "3".to_string()),
+            ],
+        })
+    );
+
+    let request = request.unwrap();
+
+    assert_eq!(request.get_parameter("depth"), Some("3"));
+    assert_eq!(request.get_parameter("movetime"), None);
+
+    assert_eq!(Request::parse("GET /analyze"), Err(InvalidRequest));
+    assert_eq!(
+        Request::parse("GET /analyze?state=%2 HTTP/1.1"),
+        Err(InvalidRequest)
+    );
+    assert_eq!(
+        Request::parse("GET /analyze?state=%FF HTTP/1.1"),
+        Err(InvalidRequest)
+    );
+    assert_eq!(Request::parse(""), Err(InvalidRequest));
+
+    let read = |input: &str| Request::read(&mut input.as_bytes()).unwrap();
+
+    assert_eq!(
+        read("GET /analyze HTTP/1.1\r\nHost: localhost\r\n\r\n"),
+        Request::parse("GET /analyze HTTP/1.1")
+    );
+    assert_eq!(read("GET /analyze HTTP/1.1\r\n"), Err(InvalidRequest));
+    assert_eq!(
+        read(&format!("GET /{} HTTP/1.1\r\n\r\n", "a".repeat(8192))),
+        Err(InvalidRequest)
+    );
+    assert_eq!(
+        read(&format!("GET / HTTP/1.1\r\n{}\r\n", "A: b\r\n".repeat(65))),
+        Err(InvalidRequest)
+    );
+
+    assert_eq!(
+        handle_request(&Request::parse("POST /analyze HTTP/1.1").unwrap()).status,
+        405
+    );
+}
+
+#[test]
+fn test_server() {
+    let server = Server::bind("127.0.0.1:0").unwrap();
+    let address = server.local_addr().unwrap();
+
+    thread::spawn(move || server.run(|_error| {}));
+
+    let idle = TcpStream::connect(address).unwrap();
+
+    let (status, body) = get(address, "/analyze?game=tic_tac_toe&state=XO..X....:O");
+
+    assert_eq!(status, 200);
+    assert_eq!(
+        without_time(&body),
+        "{\"game\":\"tic_tac_toe\",\"state\":\"XO..X....:O\",\
+         \"outcome\":{\"result\":\"win\",\"winner\":\"one\",\"moves\":3},\
+         \"best_moves\":[\"C\"],\"statistics\":{\"depth\":3,\"nodes\":471}}"
+    );
+
+    let (status, body) = get(
+        address,
+        "/analyze?game=tic_tac_toe&state=xo..x....:o&depth=1&movetime=60000",
+    );
+
+    assert_eq!(status, 200);
+    assert_eq!(
+        without_time(&body),
+        "{\"game\":\"tic_tac_toe\",\"state\":\"XO..X....:O\",\
+         \"outcome\":{\"result\":\"unknown\",\"score\":0},\
+         \"best_moves\":[\"C\"],\"statistics\":{\"depth\":1,\"nodes\":39}}"
+    );
+
+    let (status, body) = get(
+        address,
+        "/analyze?game=game_2048&state=0%2C0%2C0%2C0%2F0,0,0,0/0,0,0,0/2,0,0,4%3Ah&depth=2",
+    );
+
+    assert_eq!(status, 200);
+    assert_eq!(
+        without_time(&body),
+        "{\"game\":\"game_2048\",\"state\":\"0,0,0,0/0,0,0,0/0,0,0,0/2,0,0,4:h\",\
+         \"outcome\":{\"result\":\"unknown\",\"score\":0},\
+         \"best_moves\":[\"W\",\"A\",\"D\"],\"statistics\":{\"depth\":2,\"nodes\":484}}"
+    );
+
+    let (status, body) = get(
+        address,
+        "/analyze?game=game_2048&state=2,4,8,16/4,8,16,32/8,16,32,64/16,32,64,0:r&movetime=1000",
+    );
+
+    assert_eq!(status, 200);
+    assert!(body.contains("\"outcome\":{\"result\":\"win\",\"winner\":\"two\",\"moves\":0}"));
+    assert!(body.contains("\"best_moves\":[\"V,2\",\"V,4\"]"));
+
+    let errors = [
+        (
+            "/analyze?game=tic_tac_toe&state=XXX.OO...:-",
+            422,
+            "The game is already finished.",
+        ),
+        (
+            "/analyze?game=tic_tac_toe&state=XX.......:O",
+            400,
+            "Invalid state.",
+        ),
+        ("/analyze?game=tic_tac_toe", 400, "Missing state parameter."),
+        ("/analyze?state=.........:X", 400, "Missing game parameter."),
+        (
+            "/analyze?game=chess&state=.........:X",
+            400,
+            "Unknown game.",
+        ),
+        (
+            "/analyze?game=tic_tac_toe&state=.........:X&depth=x",
+            400,
+            "Invalid depth parameter.",
+        ),
+        (
+            "/analyze?game=tic_tac_toe&state=.........:X&movetime=-1",
+            400,
+            "Invalid movetime parameter.",
+        ),
+        (
+            "/analyze?game=tic_tac_toe&state=.........:X&movetime=60001",
+            400,
+            "The movetime must be at most 60000 milliseconds.",
+        ),
+        (
+            "/analyze?game=tic_tac_toe&state=.........:X&depth=10",
+            400,
+            "The depth must be at most 9.",
+        ),
+        (
+            "/analyze?game=game_2048&state=0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0:r&depth=7",
+            400,
+            "The depth must be at most 6.",
+        ),
+        ("/analyze?state=%zz", 400, "Malformed request."),
+        ("/", 404, "Unknown path."),
+    ];
+
+    for &(target, expected_status, message) in errors.iter() {
+        let (status, body) = get(address, target);
+
+        assert_eq!(status, expected_status);
+        assert_eq!(body, format!("{{\"error\":\"{}\"}}", message));
+    }
+
+    let mut response = String::new();
+    (&idle).read_to_string(&mut response).unwrap();
+
+    assert_eq!(response, "");
+}
+
+#[test]
+fn test_connection_limit() {
+    let server = Server::bind("127.0.0.1:0").unwrap();
+    let address = server.local_addr().unwrap();
+
+    thread::spawn(move || server.run(|_error| {}));
+
+    let idle: Vec<TcpStream> = (0..MAX_CONNECTIONS)
+        .map(|_| TcpStream::connect(address).unwrap())
+        .collect();
+
+    let mut response = String::new();
+    let mut rejected = TcpStream::connect(address).unwrap();
+    rejected.read_to_string(&mut response).unwrap();
+
+    assert!(response.starts_with("HTTP/1.1 503 Service Unavailable\r\n"));
+    assert!(response.ends_with("\r\n\r\n{\"error\":\"Too many connections.\"}"));
+
+    drop(idle);
+}
@@ -0,0 +1,53 @@
+use crate::*;
+
+use std::fmt;
+
+fn get_char(piece: Option<Piece>) -> char {
+    match piece {
+        None => '.',
+        Some(Piece::Man(Player::Black)) => 'b',
+        Some(Piece::Man(Player::White)) => 'w',
+        Some(Piece::King(Player::Black)) => 'B',
+        Some(Piece::King(Player::White)) => 'W',
+    }
+}
+
+impl fmt::Display for Player {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Player::Black => write!(f, "Black"),
+            Player::White => write!(f, "White"),
+        }
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let separator = if self.is_capture() { "x" } else { "-" };
+
+        let squares: Vec<String> = self
+            .get_squares()
+            .iter()
+            .map(|square| square.get_number().to_string())
+            .collect();
+
+        write!(f, "{}", squares.join(separator))
+    }
+}
+
+impl fmt::Display for Checkers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in 0..BOARD_SIZE {
+            for column in 0..BOARD_SIZE {
+                match Square::from_row_column(row, column) {
+                    Ok(square) => write!(f, " {}", get_char(self[square]))?,
+                    Err(_) => write!(f, "  ")?,
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
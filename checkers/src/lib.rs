@@ -0,0 +1,437 @@
+mod display;
+mod minimax;
+
+#[cfg(test)]
+mod test;
+
+use std::ops::Index;
+
+const BOARD_SIZE: usize = 8;
+const SQUARE_COUNT: usize = BOARD_SIZE * BOARD_SIZE / 2;
+const SQUARES_PER_ROW: usize = BOARD_SIZE / 2;
+const STARTING_ROWS: usize = 3;
+const QUIET_PLY_LIMIT: usize = 80;
+const REPETITION_LIMIT: usize = 3;
+const MAN_VALUE: isize = 100;
+const KING_VALUE: isize = 160;
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Square(usize);
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct InvalidSquare;
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Player {
+    Black,
+    White,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Piece {
+    Man(Player),
+    King(Player),
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Move {
+    squares: Vec<Square>,
+    captures: Vec<Square>,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum GameResult {
+    Draw,
+    Win(Player),
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Status {
+    Running(Player),
+    Finished(GameResult),
+}
+
+type Board = [Option<Piece>; SQUARE_COUNT];
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Checkers {
+    status: Status,
+    board: Board,
+    quiet_plies: usize,
+    history: Vec<(Board, Player)>,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum MoveError {
+    InvalidStatus(Status),
+    WrongPlayer(Player),
+    CaptureRequired,
+    IllegalMove(Move),
+}
+
+impl Square {
+    pub fn new(number: usize) -> Result<Square, InvalidSquare> {
+        if number == 0 || number > SQUARE_COUNT {
+            Err(InvalidSquare)
+        } else {
+            Ok(Square(number - 1))
+        }
+    }
+
+    pub fn get_number(self) -> usize {
+        self.0 + 1
+    }
+
+    pub fn from_row_column(row: usize, column: usize) -> Result<Square, InvalidSquare> {
+        if row >= BOARD_SIZE || column >= BOARD_SIZE || (row + column) % 2 != 1 {
+            Err(InvalidSquare)
+        } else {
+            Ok(Square(row * SQUARES_PER_ROW + column / 2))
+        }
+    }
+
+    pub fn get_row_column(self) -> (usize, usize) {
+        let row = self.0 / SQUARES_PER_ROW;
+        let column = 2 * (self.0 % SQUARES_PER_ROW) + (row + 1) % 2;
+
+        (row, column)
+    }
+
+    pub fn values() -> impl Iterator<Item = Square> {
+        (0..SQUARE_COUNT).map(Square)
+    }
+
+    fn offset(self, row_step: isize, column_step: isize) -> Option<Square> {
+        let (row, column) = self.get_row_column();
+        let row = row as isize + row_step;
+        let column = column as isize + column_step;
+
+        if row < 0 || column < 0 {
+            None
+        } else {
+            Square::from_row_column(row as usize, column as usize).ok()
+        }
+    }
+}
+
+impl Player {
+    pub fn other(self) -> Player {
+        match self {
+            Player::Black => Player::White,
+            Player::White => Player::Black,
+        }
+    }
+
+    fn get_forward_step(self) -> isize {
+        match self {
+            Player::Black => 1,
+            Player::White => -1,
+        }
+    }
+
+    fn get_king_row(self) -> usize {
+        match self {
+            Player::Black => BOARD_SIZE - 1,
+            Player::White => 0,
+        }
+    }
+}
+
+impl Piece {
+    pub fn get_player(self) -> Player {
+        match self {
+            Piece::Man(player) | Piece::King(player) => player,
+        }
+    }
+
+    fn get_row_steps(self) -> Vec<isize> {
+        match self {
+            Piece::Man(player) => vec![player.get_forward_step()],
+            Piece::King(_) => vec![-1, 1],
+        }
+    }
+}
+
+impl Move {
+    pub fn new(squares: Vec<Square>) -> Move {
+        Move {
+            squares,
+            captures: Vec::new(),
+        }
+    }
+
+    pub fn get_squares(&self) -> &[Square] {
+        &self.squares
+    }
+
+    pub fn get_captures(&self) -> &[Square] {
+        &self.captures
+    }
+
+    pub fn is_capture(&self) -> bool {
+        !self.captures.is_empty()
+    }
+
+    fn get_path(&self) -> (Square, Square) {
+        (self.squares[0], self.squares[self.squares.len() - 1])
+    }
+}
+
+impl PartialEq<[Square]> for Move {
+    fn eq(&self, squares: &[Square]) -> bool {
+        self.squares.as_slice() == squares
+    }
+}
+
+impl GameResult {
+    pub fn winner(self) -> Option<Player> {
+        match self {
+            GameResult::Draw => None,
+            GameResult::Win(player) => Some(player),
+        }
+    }
+}
+
+impl Checkers {
+    pub fn new() -> Checkers {
+        let mut board = [None; SQUARE_COUNT];
+
+        for square in Square::values() {
+            let (row, _column) = square.get_row_column();
+
+            if row < STARTING_ROWS {
+                board[square.0] = Some(Piece::Man(Player::Black));
+            } else if row >= BOARD_SIZE - STARTING_ROWS {
+                board[square.0] = Some(Piece::Man(Player::White));
+            }
+        }
+
+        Checkers::from_board(board, Player::Black)
+    }
+
+    pub fn from_board(board: [Option<Piece>; SQUARE_COUNT], player: Player) -> Checkers {
+        let mut game = Checkers {
+            status: Status::Running(player),
+            board,
+            quiet_plies: 0,
+            history: vec![(board, player)],
+        };
+
+        if game.get_player_moves(player).is_empty() {
+            game.status = Status::Finished(GameResult::Win(player.other()));
+        }
+
+        game
+    }
+
+    pub fn get_status(&self) -> Status {
+        self.status
+    }
+
+    pub fn get_quiet_plies(&self) -> usize {
+        self.quiet_plies
+    }
+
+    fn get_steps(&self, square: Square, piece: Piece) -> Vec<Move> {
+        piece
+            .get_row_steps()
+            .into_iter()
+            .flat_map(|row_step| vec![(row_step, -1), (row_step, 1)])
+            .filter_map(|(row_step, column_step)| square.offset(row_step, column_step))
+            .filter(|&target| self[target].is_none())
+            .map(|target| Move::new(vec![square, target]))
+            .collect()
+    }
+
+    fn collect_jumps(&self, piece: Piece, path: &mut Move, jumps: &mut Vec<Move>) {
+        let square = path.squares[path.squares.len() - 1];
+        let (row, _column) = square.get_row_column();
+        let origin = path.squares[0];
+
+        let promoted = match piece {
+            Piece::Man(player) => path.is_capture() && row == player.get_king_row(),
+            Piece::King(_) => false,
+        };
+
+        let mut extended = false;
+
+        if !promoted {
+            for row_step in piece.get_row_steps() {
+                for &column_step in [-1, 1].iter() {
+                    let captured = match square.offset(row_step, column_step) {
+                        Some(captured) => captured,
+                        None => continue,
+                    };
+
+                    let target = match square.offset(2 * row_step, 2 * column_step) {
+                        Some(target) => target,
+                        None => continue,
+                    };
+
+                    let is_opponent = match self[captured] {
+                        Some(p) => p.get_player() != piece.get_player(),
+                        None => false,
+                    };
+
+                    if !is_opponent
+                        || path.captures.contains(&captured)
+                        || (self[target].is_some() && target != origin)
+                    {
+                        continue;
+                    }
+
+                    path.squares.push(target);
+                    path.captures.push(captured);
+
+                    self.collect_jumps(piece, path, jumps);
+
+                    path.squares.pop();
+                    path.captures.pop();
+
+                    extended = true;
+                }
+            }
+        }
+
+        if !extended && path.is_capture() {
+            jumps.push(path.clone());
+        }
+    }
+
+    fn get_player_moves(&self, player: Player) -> Vec<Move> {
+        let pieces: Vec<(Square, Piece)> = Square::values()
+            .filter_map(|square| match self[square] {
+                Some(piece) if piece.get_player() == player => Some((square, piece)),
+                _ => None,
+            })
+            .collect();
+
+        let mut jumps = Vec::new();
+
+        for &(square, piece) in pieces.iter() {
+            self.collect_jumps(piece, &mut Move::new(vec![square]), &mut jumps);
+        }
+
+        if !jumps.is_empty() {
+            return jumps;
+        }
+
+        pieces
+            .into_iter()
+            .flat_map(|(square, piece)| self.get_steps(square, piece))
+            .collect()
+    }
+
+    pub fn possible_moves(&self) -> Vec<Move> {
+        match self.status {
+            Status::Running(player) => self.get_player_moves(player),
+            Status::Finished(_) => Vec::new(),
+        }
+    }
+
+    pub fn make_move(&mut self, player: Player, mov: &[Square]) -> Result<(), MoveError> {
+        let status_player = match self.status {
+            Status::Finished(_) => return Err(MoveError::InvalidStatus(self.status)),
+            Status::Running(status_player) => status_player,
+        };
+
+        if status_player != player {
+            return Err(MoveError::WrongPlayer(status_player));
+        }
+
+        let moves = self.get_player_moves(player);
+
+        let mov = match moves.into_iter().find(|m| m == mov) {
+            Some(mov) => mov,
+            None => {
+                let is_step = match (mov.first(), mov.get(1), mov.len()) {
+                    (Some(&from), Some(&to), 2) => match self[from] {
+                        Some(piece) if piece.get_player() == player => self
+                            .get_steps(from, piece)
+                            .iter()
+                            .any(|m| m.squares[1] == to),
+                        _ => false,
+                    },
+                    _ => false,
+                };
+
+                return Err(if is_step {
+                    MoveError::CaptureRequired
+                } else {
+                    MoveError::IllegalMove(Move::new(mov.to_vec()))
+                });
+            }
+        };
+
+        self.apply_move(player, &mov);
+        Ok(())
+    }
+
+    fn apply_move(&mut self, player: Player, mov: &Move) {
+        let (from, to) = mov.get_path();
+        let piece = self.board[from.0].take().expect("Empty square.");
+        let (row, _column) = to.get_row_column();
+
+        for captured in mov.captures.iter() {
+            self.board[captured.0] = None;
+        }
+
+        self.board[to.0] = Some(match piece {
+            Piece::Man(owner) if row == owner.get_king_row() => Piece::King(owner),
+            _ => piece,
+        });
+
+        let irreversible = mov.is_capture() || matches!(piece, Piece::Man(_));
+        let next_player = player.other();
+
+        if irreversible {
+            self.quiet_plies = 0;
+            self.history.clear();
+        } else {
+            self.quiet_plies += 1;
+        }
+
+        self.history.push((self.board, next_player));
+
+        let repetitions = self
+            .history
+            .iter()
+            .filter(|&&(board, p)| board == self.board && p == next_player)
+            .count();
+
+        self.status = if self.get_player_moves(next_player).is_empty() {
+            Status::Finished(GameResult::Win(player))
+        } else if repetitions >= REPETITION_LIMIT || self.quiet_plies >= QUIET_PLY_LIMIT {
+            Status::Finished(GameResult::Draw)
+        } else {
+            Status::Running(next_player)
+        };
+    }
+
+    pub fn get_material_score(&self) -> isize {
+        self.board
+            .iter()
+            .filter_map(|&piece| piece)
+            .map(|piece| match piece {
+                Piece::Man(Player::Black) => MAN_VALUE,
+                Piece::Man(Player::White) => -MAN_VALUE,
+                Piece::King(Player::Black) => KING_VALUE,
+                Piece::King(Player::White) => -KING_VALUE,
+            })
+            .sum()
+    }
+}
+
+impl Default for Checkers {
+    fn default() -> Checkers {
+        Checkers::new()
+    }
+}
+
+impl Index<Square> for Checkers {
+    type Output = Option<Piece>;
+
+    fn index(&self, square: Square) -> &Option<Piece> {
+        &self.board[square.0]
+    }
+}
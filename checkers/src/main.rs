@@ -0,0 +1,141 @@
+use ::minimax::{GameState, MinimaxResult};
+
+use std::io;
+
+use checkers::*;
+
+use checkers::GameResult::*;
+use checkers::MoveError::*;
+use checkers::Status::*;
+
+enum CheckersError {
+    MoveError(MoveError),
+    InvalidInput(String),
+}
+
+impl From<MoveError> for CheckersError {
+    fn from(error: MoveError) -> CheckersError {
+        CheckersError::MoveError(error)
+    }
+}
+
+fn print_instructions() {
+    println!("-----------------------------");
+    println!("           CHECKERS          ");
+    println!("-----------------------------");
+    println!();
+    println!("The dark squares are numbered from 1 to 32, starting at the top left corner.");
+    println!("Enter the squares of a move separated by dashes (e.g. 11-15) and ENTER.");
+    println!("For multiple jumps, enter every landing square (e.g. 22x15x8).");
+    println!();
+}
+
+fn print_minimax(game: &Checkers) -> MinimaxResult<Checkers> {
+    const MINIMAX_DEPTH: usize = 5;
+
+    print!("Minimax: ");
+
+    let minimax = match game.minimax(MINIMAX_DEPTH) {
+        Ok(minimax) => {
+            println!("{:?}", minimax.outcome);
+            minimax
+        }
+        Err(error) => {
+            println!("{:?}\n", error);
+            return Err(error);
+        }
+    };
+
+    let moves: Vec<String> = minimax.moves.iter().map(|mov| mov.to_string()).collect();
+
+    println!("{}\n", moves.join(", "));
+    Ok(minimax)
+}
+
+fn parse_input(input: String) -> Result<Vec<Square>, CheckersError> {
+    let squares: Option<Vec<Square>> = input
+        .split(&['-', 'X'][..])
+        .map(|number| number.trim().parse::<usize>().ok())
+        .map(|number| number.and_then(|number| Square::new(number).ok()))
+        .collect();
+
+    match squares {
+        Some(squares) if squares.len() >= 2 => Ok(squares),
+        _ => Err(CheckersError::InvalidInput(input)),
+    }
+}
+
+fn handle_turn(game: &mut Checkers, player: Player) -> Result<(), CheckersError> {
+    let input = {
+        let mut buffer = String::new();
+
+        io::stdin()
+            .read_line(&mut buffer)
+            .expect("It was not possible to read user input.");
+
+        println!();
+
+        buffer.trim().to_uppercase()
+    };
+
+    let squares = parse_input(input)?;
+
+    game.make_move(player, &squares)?;
+    Ok(())
+}
+
+fn handle_error(error: CheckersError) {
+    match error {
+        CheckersError::MoveError(InvalidStatus(_)) => {
+            panic!("Cannot make any move now because the status of the game doesn't allow it.");
+        }
+        CheckersError::MoveError(WrongPlayer(_)) => {
+            panic!("Cannot make that move because it's not the player's turn.");
+        }
+        CheckersError::MoveError(CaptureRequired) => {
+            println!("Cannot make that move because a capture is available.");
+        }
+        CheckersError::MoveError(IllegalMove(mov)) => {
+            println!("Cannot make the move {} because it's not legal.", mov);
+        }
+        CheckersError::InvalidInput(input) => {
+            println!("Invalid input: {}", input);
+            println!("Please, enter at least two squares from 1 to 32, separated by dashes.");
+        }
+    }
+
+    println!();
+}
+
+fn main() {
+    print_instructions();
+
+    let mut game = Checkers::new();
+
+    println!("{}", game);
+
+    loop {
+        match game.get_status() {
+            Running(player) => {
+                println!("{} to move.", player);
+                print_minimax(&game).ok();
+
+                let result = handle_turn(&mut game, player);
+
+                if let Err(error) = result {
+                    handle_error(error);
+                }
+
+                println!("{}", game);
+            }
+            Finished(result) => {
+                match result {
+                    Draw => println!("Draw."),
+                    Win(player) => println!("{} wins.", player),
+                }
+
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use crate::GameResult::*;
+use crate::MoveError::*;
+use crate::Status::*;
+use crate::*;
+
+use ::minimax::Outcome::*;
+use ::minimax::{GameResult as MinimaxGameResult, GameState};
+
+fn square(number: usize) -> Square {
+    Square::new(number).unwrap()
+}
+
+fn path(numbers: &[usize]) -> Vec<Square> {
+    numbers.iter().map(|&number| square(number)).collect()
+}
+
+fn board(pieces: &[(usize, Piece)]) -> [Option<Piece>; SQUARE_COUNT] {
+    let mut board = [None; SQUARE_COUNT];
+
+    for &(number, piece) in pieces.iter() {
+        board[square(number).0] = Some(piece);
+    }
+
+    board
+}
+
+#[test]
+fn test_squares() {
+    assert_eq!(Square::new(0), Err(InvalidSquare));
+    assert_eq!(Square::new(33), Err(InvalidSquare));
+    assert_eq!(square(1).get_row_column(), (0, 1));
+    assert_eq!(square(5).get_row_column(), (1, 0));
+    assert_eq!(square(32).get_row_column(), (7, 6));
+    assert_eq!(Square::from_row_column(0, 0), Err(InvalidSquare));
+
+    for s in Square::values() {
+        let (row, column) = s.get_row_column();
+        assert_eq!(Square::from_row_column(row, column), Ok(s));
+    }
+}
+
+#[test]
+fn test_checkers() {
+    let mut game = Checkers::new();
+
+    let moves: Vec<Vec<Square>> = game
+        .possible_moves()
+        .iter()
+        .map(|mov| mov.get_squares().to_vec())
+        .collect();
+
+    assert_eq!(
+        moves,
+        vec![
+            path(&[9, 13]),
+            path(&[9, 14]),
+            path(&[10, 14]),
+            path(&[10, 15]),
+            path(&[11, 15]),
+            path(&[11, 16]),
+            path(&[12, 16]),
+        ]
+    );
+
+    assert_eq!(
+        game.make_move(Player::White, &path(&[21, 17])),
+        Err(WrongPlayer(Player::Black))
+    );
+
+    assert_eq!(
+        game.make_move(Player::Black, &path(&[9, 18])),
+        Err(IllegalMove(Move::new(path(&[9, 18]))))
+    );
+
+    assert_eq!(game.make_move(Player::Black, &path(&[11, 15])), Ok(()));
+    assert_eq!(game[square(11)], None);
+    assert_eq!(game[square(15)], Some(Piece::Man(Player::Black)));
+    assert_eq!(game.get_status(), Running(Player::White));
+    assert_eq!(game.get_quiet_plies(), 0);
+}
+
+#[test]
+fn test_capture() {
+    let mut game = Checkers::from_board(
+        board(&[
+            (1, Piece::Man(Player::Black)),
+            (14, Piece::Man(Player::Black)),
+            (18, Piece::Man(Player::White)),
+            (27, Piece::Man(Player::White)),
+        ]),
+        Player::Black,
+    );
+
+    let moves = game.possible_moves();
+    assert_eq!(moves.len(), 1);
+    assert_eq!(moves[0].get_squares(), path(&[14, 23, 32]).as_slice());
+    assert_eq!(moves[0].get_captures(), path(&[18, 27]).as_slice());
+
+    assert_eq!(
+        game.make_move(Player::Black, &path(&[1, 5])),
+        Err(CaptureRequired)
+    );
+
+    assert_eq!(
+        game.make_move(Player::Black, &path(&[14, 23])),
+        Err(IllegalMove(Move::new(path(&[14, 23]))))
+    );
+
+    let minimax = game.minimax(2).unwrap();
+    assert_eq!(
+        minimax.outcome,
+        Definite(MinimaxGameResult::Win(Player::Black.into()), 0)
+    );
+    assert_eq!(minimax.moves, moves);
+
+    assert_eq!(game.make_move(Player::Black, &path(&[14, 23, 32])), Ok(()));
+    assert_eq!(game[square(18)], None);
+    assert_eq!(game[square(27)], None);
+    assert_eq!(game[square(32)], Some(Piece::King(Player::Black)));
+    assert_eq!(game.get_status(), Finished(Win(Player::Black)));
+
+    assert_eq!(
+        game.make_move(Player::White, &path(&[27, 23])),
+        Err(InvalidStatus(Finished(Win(Player::Black))))
+    );
+}
+
+#[test]
+fn test_repetition() {
+    let mut game = Checkers::from_board(
+        board(&[
+            (1, Piece::King(Player::Black)),
+            (32, Piece::King(Player::White)),
+        ]),
+        Player::Black,
+    );
+
+    for _ in 0..2 {
+        assert_eq!(game.get_status(), Running(Player::Black));
+        assert_eq!(game.make_move(Player::Black, &path(&[1, 5])), Ok(()));
+        assert_eq!(game.make_move(Player::White, &path(&[32, 28])), Ok(()));
+        assert_eq!(game.make_move(Player::Black, &path(&[5, 1])), Ok(()));
+        assert_eq!(game.make_move(Player::White, &path(&[28, 32])), Ok(()));
+    }
+
+    assert_eq!(game.get_quiet_plies(), 8);
+    assert_eq!(game.get_status(), Finished(Draw));
+}
+
+#[test]
+fn test_blocked() {
+    let game = Checkers::from_board(
+        board(&[
+            (29, Piece::Man(Player::Black)),
+            (5, Piece::Man(Player::White)),
+        ]),
+        Player::Black,
+    );
+
+    assert_eq!(game.get_status(), Finished(Win(Player::White)));
+    assert!(game.possible_moves().is_empty());
+}
+
+#[test]
+fn test_score() {
+    let game = Checkers::new();
+
+    assert_eq!(game.get_material_score(), 0);
+    assert_eq!(GameState::get_score(&game), 0);
+
+    let game = Checkers::from_board(
+        board(&[
+            (10, Piece::King(Player::Black)),
+            (20, Piece::Man(Player::White)),
+        ]),
+        Player::White,
+    );
+
+    assert_eq!(game.get_material_score(), KING_VALUE - MAN_VALUE);
+}
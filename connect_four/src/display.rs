@@ -0,0 +1,50 @@
+use crate::*;
+
+use std::fmt;
+
+fn get_char(player: Option<Player>) -> char {
+    match player {
+        None => ' ',
+        Some(Player::Red) => 'R',
+        Some(Player::Yellow) => 'Y',
+    }
+}
+
+impl fmt::Display for Player {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Player::Red => write!(f, "Red"),
+            Player::Yellow => write!(f, "Yellow"),
+        }
+    }
+}
+
+impl fmt::Display for ConnectFour {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in (0..GRID_HEIGHT).rev() {
+            write!(f, "|")?;
+
+            for x in 0..GRID_WIDTH {
+                let place = Place::from_xy(x, y).expect("Invalid place.");
+                write!(f, " {} |", get_char(self[place]))?;
+            }
+
+            writeln!(f)?;
+        }
+
+        write!(f, "+")?;
+
+        for _ in 0..GRID_WIDTH {
+            write!(f, "---+")?;
+        }
+
+        writeln!(f)?;
+        write!(f, " ")?;
+
+        for x in 0..GRID_WIDTH {
+            write!(f, " {}  ", x + 1)?;
+        }
+
+        writeln!(f)
+    }
+}
@@ -0,0 +1,270 @@
+mod display;
+mod minimax;
+
+#[cfg(test)]
+mod test;
+
+use std::ops::Index;
+
+const GRID_WIDTH: usize = 7;
+const GRID_HEIGHT: usize = 6;
+const GRID_SIZE: usize = GRID_WIDTH * GRID_HEIGHT;
+const LINE_LENGTH: usize = 4;
+const DIRECTIONS: [(isize, isize); 4] = [(1, 0), (0, 1), (1, 1), (1, -1)];
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Column(usize);
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct InvalidColumn;
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Place(usize);
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Player {
+    Red,
+    Yellow,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum GameResult {
+    Draw,
+    Win(Player),
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Status {
+    Running(Player),
+    Finished(GameResult),
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ConnectFour {
+    status: Status,
+    grid: [Option<Player>; GRID_SIZE],
+    heights: [usize; GRID_WIDTH],
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum MoveError {
+    InvalidStatus(Status),
+    WrongPlayer(Player),
+    ColumnFull(Column),
+}
+
+impl Column {
+    pub fn new(x: usize) -> Result<Column, InvalidColumn> {
+        if x >= GRID_WIDTH {
+            Err(InvalidColumn)
+        } else {
+            Ok(Column(x))
+        }
+    }
+
+    pub fn get_x(self) -> usize {
+        self.0
+    }
+
+    pub fn values() -> impl Iterator<Item = Column> {
+        (0..GRID_WIDTH).map(Column)
+    }
+}
+
+impl Place {
+    pub fn from_xy(x: usize, y: usize) -> Option<Place> {
+        if x >= GRID_WIDTH || y >= GRID_HEIGHT {
+            None
+        } else {
+            Some(Place(y * GRID_WIDTH + x))
+        }
+    }
+
+    pub fn get_xy(self) -> (usize, usize) {
+        (self.0 % GRID_WIDTH, self.0 / GRID_WIDTH)
+    }
+
+    fn offset(self, dx: isize, dy: isize) -> Option<Place> {
+        let (x, y) = self.get_xy();
+        let x = x as isize + dx;
+        let y = y as isize + dy;
+
+        if x < 0 || y < 0 {
+            None
+        } else {
+            Place::from_xy(x as usize, y as usize)
+        }
+    }
+}
+
+impl Player {
+    pub fn other(self) -> Player {
+        match self {
+            Player::Red => Player::Yellow,
+            Player::Yellow => Player::Red,
+        }
+    }
+}
+
+impl GameResult {
+    pub fn winner(self) -> Option<Player> {
+        match self {
+            GameResult::Draw => None,
+            GameResult::Win(player) => Some(player),
+        }
+    }
+}
+
+impl ConnectFour {
+    pub fn new() -> ConnectFour {
+        ConnectFour {
+            status: Status::Running(Player::Red),
+            grid: [None; GRID_SIZE],
+            heights: [0; GRID_WIDTH],
+        }
+    }
+
+    pub fn get_status(&self) -> Status {
+        self.status
+    }
+
+    pub fn get_height(&self, column: Column) -> usize {
+        self.heights[column.0]
+    }
+
+    pub fn possible_moves(&self) -> Vec<Column> {
+        match self.status {
+            Status::Running(_) => Column::values()
+                .filter(|&c| self.get_height(c) < GRID_HEIGHT)
+                .collect(),
+            Status::Finished(_) => Vec::new(),
+        }
+    }
+
+    fn count_direction(&self, place: Place, player: Player, dx: isize, dy: isize) -> usize {
+        let mut count = 0;
+        let mut cursor = place.offset(dx, dy);
+
+        while let Some(p) = cursor {
+            if self[p] != Some(player) {
+                break;
+            }
+
+            count += 1;
+            cursor = p.offset(dx, dy);
+        }
+
+        count
+    }
+
+    fn check_win(&self, place: Place, player: Player) -> Option<GameResult> {
+        let won = DIRECTIONS.iter().any(|&(dx, dy)| {
+            1 + self.count_direction(place, player, dx, dy)
+                + self.count_direction(place, player, -dx, -dy)
+                >= LINE_LENGTH
+        });
+
+        let all_filled = self.heights.iter().all(|&h| h == GRID_HEIGHT);
+
+        if won {
+            Some(GameResult::Win(player))
+        } else if all_filled {
+            Some(GameResult::Draw)
+        } else {
+            None
+        }
+    }
+
+    pub fn make_move(&mut self, player: Player, column: Column) -> Result<(), MoveError> {
+        match self.status {
+            Status::Finished(_) => Err(MoveError::InvalidStatus(self.status)),
+            Status::Running(status_player) => {
+                let height = self.get_height(column);
+
+                if height == GRID_HEIGHT {
+                    return Err(MoveError::ColumnFull(column));
+                } else if status_player != player {
+                    return Err(MoveError::WrongPlayer(status_player));
+                }
+
+                let place = Place::from_xy(column.0, height).expect("Invalid place.");
+
+                self.grid[place.0] = Some(player);
+                self.heights[column.0] += 1;
+
+                self.status = if let Some(result) = self.check_win(place, player) {
+                    Status::Finished(result)
+                } else {
+                    Status::Running(player.other())
+                };
+
+                Ok(())
+            }
+        }
+    }
+
+    fn get_window_owner(&self, place: Place, dx: isize, dy: isize) -> Option<(Player, usize)> {
+        let mut owner = None;
+        let mut count = 0;
+        let mut cursor = Some(place);
+
+        for _ in 0..LINE_LENGTH {
+            let p = cursor?;
+
+            if let Some(player) = self[p] {
+                match owner {
+                    Some(owner) if owner != player => return None,
+                    _ => owner = Some(player),
+                }
+
+                count += 1;
+            }
+
+            cursor = p.offset(dx, dy);
+        }
+
+        owner.map(|player| (player, count))
+    }
+
+    pub fn get_threat_score(&self) -> isize {
+        let mut score = 0;
+
+        for i in 0..GRID_SIZE {
+            for &(dx, dy) in DIRECTIONS.iter() {
+                let threat = match self.get_window_owner(Place(i), dx, dy) {
+                    Some((player, count)) => {
+                        let value = match count {
+                            1 => 1,
+                            2 => 4,
+                            _ => 32,
+                        };
+
+                        match player {
+                            Player::Red => value,
+                            Player::Yellow => -value,
+                        }
+                    }
+                    None => 0,
+                };
+
+                score += threat;
+            }
+        }
+
+        score
+    }
+}
+
+impl Default for ConnectFour {
+    fn default() -> ConnectFour {
+        ConnectFour::new()
+    }
+}
+
+impl Index<Place> for ConnectFour {
+    type Output = Option<Player>;
+
+    fn index(&self, place: Place) -> &Option<Player> {
+        &self.grid[place.0]
+    }
+}
@@ -0,0 +1,134 @@
+use ::minimax::{GameState, MinimaxResult};
+
+use std::io;
+
+use connect_four::*;
+
+use connect_four::GameResult::*;
+use connect_four::MoveError::*;
+use connect_four::Status::*;
+
+enum ConnectFourError {
+    MoveError(MoveError),
+    InvalidInput(String),
+}
+
+impl From<MoveError> for ConnectFourError {
+    fn from(error: MoveError) -> ConnectFourError {
+        ConnectFourError::MoveError(error)
+    }
+}
+
+fn print_instructions() {
+    println!("-----------------------------");
+    println!("         CONNECT FOUR        ");
+    println!("-----------------------------");
+    println!();
+    println!("Press the number of a column (1 to 7) and ENTER to drop a disc.");
+    println!();
+}
+
+fn print_minimax(game: &ConnectFour) -> MinimaxResult<ConnectFour> {
+    const MINIMAX_DEPTH: usize = 5;
+
+    print!("Minimax: ");
+
+    let minimax = match game.minimax(MINIMAX_DEPTH) {
+        Ok(minimax) => {
+            println!("{:?}", minimax.outcome);
+            minimax
+        }
+        Err(error) => {
+            println!("{:?}\n", error);
+            return Err(error);
+        }
+    };
+
+    let columns: Vec<String> = minimax
+        .moves
+        .iter()
+        .map(|column| (column.get_x() + 1).to_string())
+        .collect();
+
+    println!("{}\n", columns.join(", "));
+    Ok(minimax)
+}
+
+fn parse_input(input: String) -> Result<Column, ConnectFourError> {
+    match input.parse::<usize>() {
+        Ok(x) if x > 0 => Column::new(x - 1).map_err(|_| ConnectFourError::InvalidInput(input)),
+        _ => Err(ConnectFourError::InvalidInput(input)),
+    }
+}
+
+fn handle_turn(game: &mut ConnectFour, player: Player) -> Result<(), ConnectFourError> {
+    let input = {
+        let mut buffer = String::new();
+
+        io::stdin()
+            .read_line(&mut buffer)
+            .expect("It was not possible to read user input.");
+
+        println!();
+
+        buffer.trim().to_string()
+    };
+
+    let column = parse_input(input)?;
+
+    game.make_move(player, column)?;
+    Ok(())
+}
+
+fn handle_error(error: ConnectFourError) {
+    match error {
+        ConnectFourError::MoveError(InvalidStatus(_)) => {
+            panic!("Cannot make any move now because the status of the game doesn't allow it.");
+        }
+        ConnectFourError::MoveError(WrongPlayer(_)) => {
+            panic!("Cannot make that move because it's not the player's turn.");
+        }
+        ConnectFourError::MoveError(ColumnFull(_)) => {
+            println!("Cannot make that move because that column is already full.");
+        }
+        ConnectFourError::InvalidInput(input) => {
+            println!("Invalid input: {}", input);
+            println!("Please, enter a column number from 1 to 7.");
+        }
+    }
+
+    println!();
+}
+
+fn main() {
+    print_instructions();
+
+    let mut game = ConnectFour::new();
+
+    println!("{}", game);
+
+    loop {
+        match game.get_status() {
+            Running(player) => {
+                println!("{} to move.", player);
+                print_minimax(&game).ok();
+
+                let result = handle_turn(&mut game, player);
+
+                if let Err(error) = result {
+                    handle_error(error);
+                }
+
+                println!("{}", game);
+            }
+            Finished(result) => {
+                match result {
+                    Draw => println!("Draw."),
+                    Win(player) => println!("{} wins.", player),
+                }
+
+                break;
+            }
+        }
+    }
+}
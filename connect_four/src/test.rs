@@ -0,0 +1,110 @@
+use crate::GameResult::*;
+use crate::MoveError::*;
+use crate::Status::*;
+use crate::*;
+
+use ::minimax::Outcome::*;
+use ::minimax::{GameResult as MinimaxGameResult, GameState};
+
+fn play(game: &mut ConnectFour, columns: &str) {
+    for c in columns.chars() {
+        let column = Column::new(c.to_digit(10).unwrap() as usize - 1).unwrap();
+        assert_eq!(GameState::make_move(game, &column), Ok(()));
+    }
+}
+
+#[test]
+fn test_connect_four() {
+    let mut game = ConnectFour::new();
+
+    assert_eq!(Column::new(7), Err(InvalidColumn));
+
+    play(&mut game, "444444");
+
+    assert_eq!(
+        game.make_move(Player::Red, Column(3)),
+        Err(ColumnFull(Column(3)))
+    );
+
+    assert_eq!(
+        game.make_move(Player::Yellow, Column(0)),
+        Err(WrongPlayer(Player::Red))
+    );
+
+    assert_eq!(game.possible_moves().len(), GRID_WIDTH - 1);
+
+    play(&mut game, "1122");
+
+    assert_eq!(game.get_status(), Running(Player::Red));
+    assert_eq!(game.make_move(Player::Red, Column(2)), Ok(()));
+    assert_eq!(game.get_status(), Finished(Win(Player::Red)));
+
+    assert_eq!(
+        game.make_move(Player::Yellow, Column(5)),
+        Err(InvalidStatus(Finished(Win(Player::Red))))
+    );
+}
+
+#[test]
+fn test_lines() {
+    let mut game = ConnectFour::new();
+    play(&mut game, "121212");
+    assert_eq!(game.get_status(), Running(Player::Red));
+    play(&mut game, "1");
+    assert_eq!(game.get_status(), Finished(Win(Player::Red)));
+
+    let mut game = ConnectFour::new();
+    play(&mut game, "1223433454");
+    assert_eq!(game.get_status(), Running(Player::Red));
+    play(&mut game, "4");
+    assert_eq!(game.get_status(), Finished(Win(Player::Red)));
+
+    let mut game = ConnectFour::new();
+    play(&mut game, "7665455434");
+    assert_eq!(game.get_status(), Running(Player::Red));
+    play(&mut game, "4");
+    assert_eq!(game.get_status(), Finished(Win(Player::Red)));
+}
+
+#[test]
+fn test_threat_score() {
+    let mut game = ConnectFour::new();
+    assert_eq!(game.get_threat_score(), 0);
+
+    play(&mut game, "4");
+    assert_eq!(game.get_threat_score(), 7);
+
+    play(&mut game, "4");
+    assert_eq!(game.get_threat_score(), 6 - 9);
+}
+
+#[test]
+fn test_draw() {
+    let mut game = ConnectFour::new();
+    play(&mut game, "44444433333355555512222226666661111177777");
+
+    assert_eq!(game.get_status(), Running(Player::Yellow));
+    assert_eq!(
+        game.minimax(0).unwrap().outcome,
+        Definite(MinimaxGameResult::Draw, 0)
+    );
+
+    play(&mut game, "7");
+
+    assert_eq!(game.get_status(), Finished(Draw));
+    assert!(game.possible_moves().is_empty());
+}
+
+#[test]
+fn test_minimax() {
+    let mut game = ConnectFour::new();
+    play(&mut game, "4455");
+
+    let minimax = game.minimax(2).unwrap();
+
+    assert_eq!(
+        minimax.outcome,
+        Definite(MinimaxGameResult::Win(Player::Red.into()), 2)
+    );
+    assert_eq!(minimax.moves, vec![Column(2), Column(5)]);
+}
@@ -0,0 +1,70 @@
+use crate::*;
+
+use std::fmt;
+
+const BOX_WIDTH: usize = 3;
+
+fn get_char(player: Option<Player>) -> char {
+    match player {
+        None => ' ',
+        Some(Player::Red) => 'R',
+        Some(Player::Blue) => 'B',
+    }
+}
+
+fn print_dots(f: &mut fmt::Formatter, game: &DotsAndBoxes, y: usize) -> fmt::Result {
+    write!(f, "+")?;
+
+    for x in 0..game.get_width() {
+        let space = if game.is_drawn(Line::Horizontal { x, y }) {
+            '-'
+        } else {
+            ' '
+        };
+
+        for _ in 0..BOX_WIDTH {
+            write!(f, "{}", space)?;
+        }
+
+        write!(f, "+")?;
+    }
+
+    writeln!(f)
+}
+
+fn print_boxes(f: &mut fmt::Formatter, game: &DotsAndBoxes, y: usize) -> fmt::Result {
+    for x in 0..=game.get_width() {
+        if game.is_drawn(Line::Vertical { x, y }) {
+            write!(f, "|")?;
+        } else {
+            write!(f, " ")?;
+        }
+
+        if x < game.get_width() {
+            write!(f, "{:^1$}", get_char(game.get_box(x, y)), BOX_WIDTH)?;
+        }
+    }
+
+    writeln!(f)
+}
+
+impl fmt::Display for Player {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Player::Red => write!(f, "Red"),
+            Player::Blue => write!(f, "Blue"),
+        }
+    }
+}
+
+impl fmt::Display for DotsAndBoxes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.get_height() {
+            print_dots(f, self, y)?;
+            print_boxes(f, self, y)?;
+        }
+
+        print_dots(f, self, self.get_height())?;
+        Ok(())
+    }
+}
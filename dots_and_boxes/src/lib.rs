@@ -0,0 +1,238 @@
+mod display;
+mod minimax;
+
+#[cfg(test)]
+mod test;
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Line {
+    Horizontal { x: usize, y: usize },
+    Vertical { x: usize, y: usize },
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Player {
+    Red,
+    Blue,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum GameResult {
+    Draw,
+    Win(Player),
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Status {
+    Running(Player),
+    Finished(GameResult),
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct DotsAndBoxes {
+    width: usize,
+    height: usize,
+    status: Status,
+    horizontal: Vec<bool>,
+    vertical: Vec<bool>,
+    boxes: Vec<Option<Player>>,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum MoveError {
+    InvalidStatus(Status),
+    WrongPlayer(Player),
+    LineOutOfBounds(Line),
+    LineAlreadyDrawn(Line),
+}
+
+impl Player {
+    pub fn other(self) -> Player {
+        match self {
+            Player::Red => Player::Blue,
+            Player::Blue => Player::Red,
+        }
+    }
+}
+
+impl GameResult {
+    pub fn winner(self) -> Option<Player> {
+        match self {
+            GameResult::Draw => None,
+            GameResult::Win(player) => Some(player),
+        }
+    }
+}
+
+impl DotsAndBoxes {
+    pub fn new(width: usize, height: usize) -> DotsAndBoxes {
+        if width == 0 || height == 0 {
+            panic!("{}x{} is not a valid grid of boxes.", width, height);
+        }
+
+        DotsAndBoxes {
+            width,
+            height,
+            status: Status::Running(Player::Red),
+            horizontal: vec![false; width * (height + 1)],
+            vertical: vec![false; (width + 1) * height],
+            boxes: vec![None; width * height],
+        }
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get_status(&self) -> Status {
+        self.status
+    }
+
+    fn get_line_index(&self, line: Line) -> Option<usize> {
+        match line {
+            Line::Horizontal { x, y } if x < self.width && y <= self.height => {
+                Some(y * self.width + x)
+            }
+            Line::Vertical { x, y } if x <= self.width && y < self.height => {
+                Some(y * (self.width + 1) + x)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn is_drawn(&self, line: Line) -> bool {
+        match (line, self.get_line_index(line)) {
+            (Line::Horizontal { .. }, Some(index)) => self.horizontal[index],
+            (Line::Vertical { .. }, Some(index)) => self.vertical[index],
+            (_, None) => false,
+        }
+    }
+
+    pub fn get_box(&self, x: usize, y: usize) -> Option<Player> {
+        if x < self.width && y < self.height {
+            self.boxes[y * self.width + x]
+        } else {
+            None
+        }
+    }
+
+    pub fn count_boxes(&self, player: Player) -> usize {
+        self.boxes.iter().filter(|&&b| b == Some(player)).count()
+    }
+
+    pub fn lines(&self) -> Vec<Line> {
+        let horizontal =
+            (0..=self.height).flat_map(|y| (0..self.width).map(move |x| Line::Horizontal { x, y }));
+
+        let vertical =
+            (0..self.height).flat_map(|y| (0..=self.width).map(move |x| Line::Vertical { x, y }));
+
+        horizontal.chain(vertical).collect()
+    }
+
+    pub fn possible_moves(&self) -> Vec<Line> {
+        match self.status {
+            Status::Running(_) => self
+                .lines()
+                .into_iter()
+                .filter(|&line| !self.is_drawn(line))
+                .collect(),
+            Status::Finished(_) => Vec::new(),
+        }
+    }
+
+    fn is_box_complete(&self, x: usize, y: usize) -> bool {
+        self.is_drawn(Line::Horizontal { x, y })
+            && self.is_drawn(Line::Horizontal { x, y: y + 1 })
+            && self.is_drawn(Line::Vertical { x, y })
+            && self.is_drawn(Line::Vertical { x: x + 1, y })
+    }
+
+    fn get_adjacent_boxes(&self, line: Line) -> Vec<(usize, usize)> {
+        let mut boxes = Vec::new();
+
+        match line {
+            Line::Horizontal { x, y } => {
+                if y > 0 {
+                    boxes.push((x, y - 1));
+                }
+
+                if y < self.height {
+                    boxes.push((x, y));
+                }
+            }
+            Line::Vertical { x, y } => {
+                if x > 0 {
+                    boxes.push((x - 1, y));
+                }
+
+                if x < self.width {
+                    boxes.push((x, y));
+                }
+            }
+        }
+
+        boxes
+    }
+
+    fn update_status(&mut self, last_player: Player, completed: bool) {
+        self.status = if self.boxes.iter().all(|b| b.is_some()) {
+            let red = self.count_boxes(Player::Red);
+            let blue = self.count_boxes(Player::Blue);
+
+            Status::Finished(if red > blue {
+                GameResult::Win(Player::Red)
+            } else if blue > red {
+                GameResult::Win(Player::Blue)
+            } else {
+                GameResult::Draw
+            })
+        } else if completed {
+            Status::Running(last_player)
+        } else {
+            Status::Running(last_player.other())
+        };
+    }
+
+    pub fn make_move(&mut self, player: Player, line: Line) -> Result<(), MoveError> {
+        match self.status {
+            Status::Finished(_) => Err(MoveError::InvalidStatus(self.status)),
+            Status::Running(status_player) => {
+                let index = self
+                    .get_line_index(line)
+                    .ok_or(MoveError::LineOutOfBounds(line))?;
+
+                if self.is_drawn(line) {
+                    return Err(MoveError::LineAlreadyDrawn(line));
+                } else if status_player != player {
+                    return Err(MoveError::WrongPlayer(status_player));
+                }
+
+                match line {
+                    Line::Horizontal { .. } => self.horizontal[index] = true,
+                    Line::Vertical { .. } => self.vertical[index] = true,
+                }
+
+                let mut completed = false;
+
+                for (x, y) in self.get_adjacent_boxes(line) {
+                    if self.is_box_complete(x, y) {
+                        self.boxes[y * self.width + x] = Some(player);
+                        completed = true;
+                    }
+                }
+
+                self.update_status(player, completed);
+                Ok(())
+            }
+        }
+    }
+
+    pub fn get_box_score(&self) -> isize {
+        self.count_boxes(Player::Red) as isize - self.count_boxes(Player::Blue) as isize
+    }
+}
@@ -0,0 +1,170 @@
+use ::minimax::{GameState, MinimaxResult};
+
+use std::env;
+use std::io;
+
+use dots_and_boxes::*;
+
+use dots_and_boxes::GameResult::*;
+use dots_and_boxes::MoveError::*;
+use dots_and_boxes::Status::*;
+
+enum DotsAndBoxesError {
+    MoveError(MoveError),
+    InvalidInput(String),
+}
+
+impl From<MoveError> for DotsAndBoxesError {
+    fn from(error: MoveError) -> DotsAndBoxesError {
+        DotsAndBoxesError::MoveError(error)
+    }
+}
+
+fn print_instructions() {
+    println!("-----------------------------");
+    println!("        DOTS AND BOXES       ");
+    println!("-----------------------------");
+    println!();
+    println!("Enter the move in the following format: <direction> <x> <y>");
+    println!("Where <direction> is H for a horizontal line or V for a vertical line,");
+    println!("and <x> <y> is the dot where the line starts, counting from the upper left.");
+    println!("Completing a box gives you another move.");
+    println!();
+}
+
+fn format_line(line: Line) -> String {
+    match line {
+        Line::Horizontal { x, y } => format!("H {} {}", x + 1, y + 1),
+        Line::Vertical { x, y } => format!("V {} {}", x + 1, y + 1),
+    }
+}
+
+fn print_minimax(game: &DotsAndBoxes) -> MinimaxResult<DotsAndBoxes> {
+    const MINIMAX_DEPTH: usize = 3;
+
+    print!("Minimax: ");
+
+    let minimax = match game.minimax(MINIMAX_DEPTH) {
+        Ok(minimax) => {
+            println!("{:?}", minimax.outcome);
+            minimax
+        }
+        Err(error) => {
+            println!("{:?}\n", error);
+            return Err(error);
+        }
+    };
+
+    let moves: Vec<String> = minimax
+        .moves
+        .iter()
+        .map(|&line| format_line(line))
+        .collect();
+
+    println!("{}\n", moves.join(", "));
+    Ok(minimax)
+}
+
+fn parse_input(input: String) -> Result<Line, DotsAndBoxesError> {
+    let words: Vec<&str> = input.split_whitespace().collect();
+
+    let coordinates: Vec<usize> = words
+        .iter()
+        .skip(1)
+        .map(|s| s.parse::<usize>())
+        .collect::<Result<_, _>>()
+        .map_err(|_| DotsAndBoxesError::InvalidInput(input.clone()))?;
+
+    match (words.first(), coordinates.as_slice()) {
+        (Some(&"H"), &[x, y]) if x > 0 && y > 0 => Ok(Line::Horizontal { x: x - 1, y: y - 1 }),
+        (Some(&"V"), &[x, y]) if x > 0 && y > 0 => Ok(Line::Vertical { x: x - 1, y: y - 1 }),
+        _ => Err(DotsAndBoxesError::InvalidInput(input)),
+    }
+}
+
+fn handle_turn(game: &mut DotsAndBoxes, player: Player) -> Result<(), DotsAndBoxesError> {
+    let input = {
+        let mut buffer = String::new();
+
+        io::stdin()
+            .read_line(&mut buffer)
+            .expect("It was not possible to read user input.");
+
+        println!();
+
+        buffer.trim().to_uppercase()
+    };
+
+    let line = parse_input(input)?;
+
+    game.make_move(player, line)?;
+    Ok(())
+}
+
+fn handle_error(error: DotsAndBoxesError) {
+    match error {
+        DotsAndBoxesError::MoveError(InvalidStatus(_)) => {
+            panic!("Cannot make any move now because the status of the game doesn't allow it.");
+        }
+        DotsAndBoxesError::MoveError(WrongPlayer(_)) => {
+            panic!("Cannot make that move because it's not the player's turn.");
+        }
+        DotsAndBoxesError::MoveError(LineOutOfBounds(_)) => {
+            println!("Cannot make that move because that line is outside the grid.");
+        }
+        DotsAndBoxesError::MoveError(LineAlreadyDrawn(_)) => {
+            println!("Cannot make that move because that line is already drawn.");
+        }
+        DotsAndBoxesError::InvalidInput(input) => {
+            println!("Invalid input: {}", input);
+        }
+    }
+
+    println!();
+}
+
+fn main() {
+    let sizes: Vec<usize> = env::args()
+        .skip(1)
+        .filter_map(|arg| arg.parse().ok())
+        .filter(|&size| size > 0)
+        .collect();
+
+    let mut game = match sizes.as_slice() {
+        [width, height] => DotsAndBoxes::new(*width, *height),
+        _ => DotsAndBoxes::new(3, 3),
+    };
+
+    print_instructions();
+    println!("{}", game);
+
+    loop {
+        match game.get_status() {
+            Running(player) => {
+                println!(
+                    "{} to move. Red {} x {} Blue.",
+                    player,
+                    game.count_boxes(Player::Red),
+                    game.count_boxes(Player::Blue)
+                );
+                print_minimax(&game).ok();
+
+                let result = handle_turn(&mut game, player);
+
+                if let Err(error) = result {
+                    handle_error(error);
+                }
+
+                println!("{}", game);
+            }
+            Finished(result) => {
+                match result {
+                    Draw => println!("Draw."),
+                    Win(player) => println!("{} wins.", player),
+                }
+
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::GameResult::*;
+use crate::MoveError::*;
+use crate::Status::*;
+use crate::*;
+
+use ::minimax::Outcome::*;
+use ::minimax::{GameResult as MinimaxGameResult, GameState};
+
+fn horizontal(x: usize, y: usize) -> Line {
+    Line::Horizontal { x, y }
+}
+
+fn vertical(x: usize, y: usize) -> Line {
+    Line::Vertical { x, y }
+}
+
+#[test]
+fn test_dots_and_boxes() {
+    let mut game = DotsAndBoxes::new(2, 1);
+
+    assert_eq!(game.possible_moves().len(), 7);
+
+    assert_eq!(
+        game.make_move(Player::Red, horizontal(2, 0)),
+        Err(LineOutOfBounds(horizontal(2, 0)))
+    );
+
+    assert_eq!(game.make_move(Player::Red, horizontal(0, 0)), Ok(()));
+
+    assert_eq!(
+        game.make_move(Player::Blue, horizontal(0, 0)),
+        Err(LineAlreadyDrawn(horizontal(0, 0)))
+    );
+
+    assert_eq!(
+        game.make_move(Player::Red, horizontal(0, 1)),
+        Err(WrongPlayer(Player::Blue))
+    );
+
+    assert_eq!(game.make_move(Player::Blue, horizontal(0, 1)), Ok(()));
+    assert_eq!(game.make_move(Player::Red, vertical(0, 0)), Ok(()));
+    assert_eq!(game.make_move(Player::Blue, vertical(1, 0)), Ok(()));
+
+    assert_eq!(game.get_box(0, 0), Some(Player::Blue));
+    assert_eq!(game.get_box_score(), -1);
+    assert_eq!(game.get_status(), Running(Player::Blue));
+
+    assert_eq!(game.make_move(Player::Blue, horizontal(1, 0)), Ok(()));
+    assert_eq!(game.make_move(Player::Red, horizontal(1, 1)), Ok(()));
+    assert_eq!(game.make_move(Player::Blue, vertical(2, 0)), Ok(()));
+
+    assert_eq!(game.count_boxes(Player::Blue), 2);
+    assert_eq!(game.get_status(), Finished(Win(Player::Blue)));
+
+    assert_eq!(
+        game.make_move(Player::Red, vertical(2, 0)),
+        Err(InvalidStatus(Finished(Win(Player::Blue))))
+    );
+}
+
+#[test]
+fn test_consecutive_turns() {
+    let mut game = DotsAndBoxes::new(2, 1);
+
+    for &line in [horizontal(0, 0), horizontal(0, 1), vertical(0, 0)].iter() {
+        assert_eq!(GameState::make_move(&mut game, &line), Ok(()));
+    }
+
+    let minimax = game.minimax(4).unwrap();
+    assert_eq!(
+        minimax.outcome,
+        Definite(MinimaxGameResult::Win(Player::Blue.into()), 3)
+    );
+    assert_eq!(minimax.moves, vec![vertical(1, 0)]);
+}
+
+#[test]
+fn test_display() {
+    let mut game = DotsAndBoxes::new(2, 2);
+
+    for &line in [
+        horizontal(0, 0),
+        vertical(0, 0),
+        horizontal(1, 2),
+        horizontal(0, 1),
+        vertical(1, 0),
+    ]
+    .iter()
+    {
+        assert_eq!(GameState::make_move(&mut game, &line), Ok(()));
+    }
+
+    assert_eq!(
+        game.to_string(),
+        concat!(
+            "+---+   +\n",
+            "| R |    \n",
+            "+---+   +\n",
+            "         \n",
+            "+   +---+\n",
+        )
+    );
+}
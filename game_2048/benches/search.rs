@@ -0,0 +1,34 @@
+use ::minimax::benchmark::BenchmarkSuite;
+use ::minimax::GameState;
+
+use game_2048::*;
+
+const POSITIONS: [(&str, &str); 3] = [
+    ("empty", "0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0:r"),
+    ("opening", "0,0,0,0/0,2,0,0/0,0,0,0/0,0,4,0:h"),
+    ("middlegame", "2,0,0,0/16,4,0,0/64,32,8,2/256,128,16,4:h"),
+];
+
+fn bench_position<S: GameState>(suite: &mut BenchmarkSuite, name: &str, game: &S) {
+    for depth in 1..=3 {
+        suite.bench_minimax(&format!("minimax/{}/depth {}", name, depth), game, depth);
+    }
+
+    for depth in 1..=3 {
+        suite.bench_perft(&format!("perft/{}/depth {}", name, depth), game, depth);
+    }
+}
+
+fn main() {
+    let mut suite = BenchmarkSuite::from_args();
+
+    for (name, position) in POSITIONS.iter() {
+        let game: Game2048 = position.parse().expect("Invalid benchmark position.");
+        bench_position(&mut suite, &format!("game/{}", name), &game);
+
+        let bitboard: Bitboard2048 = position.parse().expect("Invalid benchmark position.");
+        bench_position(&mut suite, &format!("bitboard/{}", name), &bitboard);
+    }
+
+    suite.finish();
+}
@@ -0,0 +1,15 @@
+use ::minimax::engine::Engine;
+
+use std::io;
+
+use game_2048::*;
+
+const MAX_DEPTH: usize = 8;
+
+fn main() {
+    let mut engine = Engine::new(Bitboard2048::new(), MAX_DEPTH);
+
+    engine
+        .run(io::stdin().lock(), io::stdout())
+        .expect("It was not possible to communicate through the standard streams.");
+}
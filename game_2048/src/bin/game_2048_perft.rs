@@ -0,0 +1,31 @@
+use ::minimax::perft::print_perft;
+
+use std::env;
+use std::io;
+use std::process;
+
+use game_2048::*;
+
+const DEFAULT_DEPTH: usize = 4;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let depth = args
+        .iter()
+        .filter_map(|arg| arg.parse().ok())
+        .next()
+        .unwrap_or(DEFAULT_DEPTH);
+
+    let game: Bitboard2048 = match args.iter().find(|arg| arg.parse::<usize>().is_err()) {
+        Some(state) => state
+            .parse()
+            .unwrap_or_else(|error| panic!("Invalid state {}: {:?}", state, error)),
+        None => Bitboard2048::new(),
+    };
+
+    if let Err(error) = print_perft(&game, depth, &mut io::stdout()) {
+        eprintln!("{}", error);
+        process::exit(1);
+    }
+}
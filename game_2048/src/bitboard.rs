@@ -0,0 +1,267 @@
+use crate::*;
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+const TILE_BITS: usize = 4;
+const TILE_MASK: u64 = 0xF;
+const ROW_BITS: usize = TILE_BITS * GRID_WIDTH;
+const ROW_MASK: u64 = 0xFFFF;
+const ROW_COUNT: usize = 1 << ROW_BITS;
+const MAX_EXPONENT: u16 = 15;
+
+struct RowTables {
+    left: Vec<u16>,
+    right: Vec<u16>,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Bitboard2048 {
+    status: Status,
+    board: u64,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct TileTooLarge(pub usize);
+
+impl RowTables {
+    fn new() -> RowTables {
+        let left: Vec<u16> = (0..ROW_COUNT).map(|row| slide_row(row as u16)).collect();
+
+        let right = (0..ROW_COUNT)
+            .map(|row| reverse_row(left[reverse_row(row as u16) as usize]))
+            .collect();
+
+        RowTables { left, right }
+    }
+
+    fn get() -> &'static RowTables {
+        static TABLES: OnceLock<RowTables> = OnceLock::new();
+        TABLES.get_or_init(RowTables::new)
+    }
+}
+
+fn slide_row(row: u16) -> u16 {
+    let tiles = (0..GRID_WIDTH)
+        .map(|i| (row >> (i * TILE_BITS)) & TILE_MASK as u16)
+        .filter(|&tile| tile > 0);
+
+    let mut result = [0; GRID_WIDTH];
+    let mut write_cursor = 0;
+    let mut can_merge = false;
+
+    for tile in tiles {
+        if can_merge && result[write_cursor - 1] == tile && tile < MAX_EXPONENT {
+            result[write_cursor - 1] += 1;
+            can_merge = false;
+        } else {
+            result[write_cursor] = tile;
+            write_cursor += 1;
+            can_merge = true;
+        }
+    }
+
+    result
+        .iter()
+        .enumerate()
+        .fold(0, |row, (i, &tile)| row | (tile << (i * TILE_BITS)))
+}
+
+fn reverse_row(row: u16) -> u16 {
+    (row >> 12) | ((row >> 4) & 0x00F0) | ((row << 4) & 0x0F00) | (row << 12)
+}
+
+fn transpose(board: u64) -> u64 {
+    let a1 = board & 0xF0F0_0F0F_F0F0_0F0F;
+    let a2 = board & 0x0000_F0F0_0000_F0F0;
+    let a3 = board & 0x0F0F_0000_0F0F_0000;
+    let a = a1 | (a2 << 12) | (a3 >> 12);
+
+    let b1 = a & 0xFF00_FF00_00FF_00FF;
+    let b2 = a & 0x00FF_00FF_0000_0000;
+    let b3 = a & 0x0000_0000_FF00_FF00;
+    b1 | (b2 >> 24) | (b3 << 24)
+}
+
+fn map_rows(board: u64, table: &[u16]) -> u64 {
+    (0..GRID_HEIGHT).fold(0, |result, y| {
+        let row = (board >> (y * ROW_BITS)) & ROW_MASK;
+        result | (u64::from(table[row as usize]) << (y * ROW_BITS))
+    })
+}
+
+fn slide_board(board: u64, direction: Direction) -> u64 {
+    let tables = RowTables::get();
+
+    match direction {
+        Direction::Left => map_rows(board, &tables.left),
+        Direction::Right => map_rows(board, &tables.right),
+        Direction::Down => transpose(map_rows(transpose(board), &tables.left)),
+        Direction::Up => transpose(map_rows(transpose(board), &tables.right)),
+    }
+}
+
+fn get_exponent(value: usize) -> Result<u64, TileTooLarge> {
+    match value {
+        0 => Ok(0),
+        _ if value <= Bitboard2048::MAX_TILE => Ok(u64::from(value.trailing_zeros())),
+        _ => Err(TileTooLarge(value)),
+    }
+}
+
+fn get_tile_value(exponent: u64) -> usize {
+    match exponent {
+        0 => 0,
+        _ => 1 << exponent,
+    }
+}
+
+impl Bitboard2048 {
+    pub const MAX_TILE: usize = 1 << MAX_EXPONENT;
+
+    pub fn new() -> Bitboard2048 {
+        Bitboard2048 {
+            status: Status::Running(Player::Robot),
+            board: 0,
+        }
+    }
+
+    pub fn get_status(&self) -> Status {
+        self.status
+    }
+
+    fn get_exponent(&self, place: Place) -> u64 {
+        (self.board >> (place.0 * TILE_BITS)) & TILE_MASK
+    }
+
+    pub fn get_value(&self, place: Place) -> usize {
+        get_tile_value(self.get_exponent(place))
+    }
+
+    pub fn get_values(&self) -> [usize; GRID_SIZE] {
+        let mut values = [0; GRID_SIZE];
+
+        for (i, value) in values.iter_mut().enumerate() {
+            *value = self.get_value(Place(i));
+        }
+
+        values
+    }
+
+    pub fn possible_moves(&self) -> Vec<Move> {
+        match self.status {
+            Status::Finished => Vec::new(),
+            Status::Running(Player::Human) => Direction::values()
+                .filter(|&d| slide_board(self.board, d) != self.board)
+                .map(Move::Human)
+                .collect(),
+            Status::Running(Player::Robot) => (0..GRID_SIZE)
+                .map(Place)
+                .filter(|&p| self.get_exponent(p) == 0)
+                .flat_map(|p| once(Move::Robot(p, 2)).chain(once(Move::Robot(p, 4))))
+                .collect(),
+        }
+    }
+
+    pub fn make_move(&mut self, mov: Move) -> Result<(), MoveError> {
+        match self.status {
+            Status::Finished => Err(MoveError::InvalidStatus(self.status)),
+            Status::Running(player) => {
+                if player != mov.get_player() {
+                    return Err(MoveError::WrongPlayer(player));
+                }
+
+                match mov {
+                    Move::Human(direction) => self.make_human_move(direction),
+                    Move::Robot(place, value) => self.make_robot_move(place, value),
+                }
+            }
+        }
+    }
+
+    fn make_human_move(&mut self, direction: Direction) -> Result<(), MoveError> {
+        let board = slide_board(self.board, direction);
+
+        if board == self.board {
+            return Err(MoveError::DirectionBlocked(direction));
+        }
+
+        self.board = board;
+        self.status = Status::Running(Player::Robot);
+        Ok(())
+    }
+
+    fn make_robot_move(&mut self, place: Place, value: usize) -> Result<(), MoveError> {
+        if self.get_exponent(place) > 0 {
+            return Err(MoveError::PlaceAlreadyFilled(place));
+        }
+
+        if ![2, 4].contains(&value) {
+            return Err(MoveError::ValueNotAllowed(value));
+        }
+
+        self.board |= u64::from(value.trailing_zeros()) << (place.0 * TILE_BITS);
+        self.status = Status::Running(Player::Human);
+
+        if self.possible_moves().is_empty() {
+            self.status = Status::Finished
+        }
+
+        Ok(())
+    }
+
+    pub fn to_compact_string(&self) -> String {
+        Game2048::from(self).to_compact_string()
+    }
+}
+
+impl Default for Bitboard2048 {
+    fn default() -> Bitboard2048 {
+        Bitboard2048::new()
+    }
+}
+
+impl FromStr for Bitboard2048 {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Bitboard2048, ParseError> {
+        let game: Game2048 = input.parse()?;
+        Bitboard2048::try_from(&game).map_err(|TileTooLarge(value)| ParseError::InvalidValue(value))
+    }
+}
+
+impl TryFrom<&Game2048> for Bitboard2048 {
+    type Error = TileTooLarge;
+
+    fn try_from(game: &Game2048) -> Result<Bitboard2048, TileTooLarge> {
+        let board = game
+            .grid
+            .iter()
+            .enumerate()
+            .try_fold(0, |board, (i, &value)| {
+                Ok(board | (get_exponent(value)? << (i * TILE_BITS)))
+            })?;
+
+        Ok(Bitboard2048 {
+            status: game.status,
+            board,
+        })
+    }
+}
+
+impl From<&Bitboard2048> for Game2048 {
+    fn from(game: &Bitboard2048) -> Game2048 {
+        Game2048 {
+            status: game.status,
+            grid: game.get_values(),
+        }
+    }
+}
+
+impl fmt::Display for Bitboard2048 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Game2048::from(self).fmt(f)
+    }
+}
@@ -1,5 +1,4 @@
-#![feature(try_from)]
-
+mod bitboard;
 mod display;
 mod minimax;
 
@@ -9,6 +8,9 @@ mod test;
 use std::iter::once;
 use std::ops::Add;
 use std::ops::Index;
+use std::str::FromStr;
+
+pub use crate::bitboard::{Bitboard2048, TileTooLarge};
 
 const GRID_WIDTH: usize = 4;
 const GRID_HEIGHT: usize = 4;
@@ -61,6 +63,18 @@ pub enum MoveError {
     DirectionBlocked(Direction),
 }
 
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ParseError {
+    MissingSide,
+    InvalidSide,
+    InvalidRowCount(usize),
+    InvalidRowLength(usize),
+    InvalidNumber,
+    InvalidValue(usize),
+    EmptyGrid,
+    RobotCannotMove,
+}
+
 impl Place {
     pub fn from_xy(x: usize, y: usize) -> Result<Place, InvalidPlace> {
         if x >= GRID_WIDTH || y >= GRID_HEIGHT {
@@ -271,6 +285,87 @@ impl Game2048 {
 
         Ok(())
     }
+
+    pub fn to_compact_string(&self) -> String {
+        let rows: Vec<String> = (0..GRID_HEIGHT)
+            .rev()
+            .map(|y| {
+                let values: Vec<String> = (0..GRID_WIDTH)
+                    .map(|x| self.grid[y * GRID_WIDTH + x].to_string())
+                    .collect();
+
+                values.join(",")
+            })
+            .collect();
+
+        let player = match self.status {
+            Status::Running(Player::Robot) => "r",
+            Status::Running(Player::Human) | Status::Finished => "h",
+        };
+
+        format!("{}:{}", rows.join("/"), player)
+    }
+}
+
+impl FromStr for Game2048 {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Game2048, ParseError> {
+        let (grid, side) = input
+            .trim()
+            .split_once(':')
+            .ok_or(ParseError::MissingSide)?;
+
+        let player = match side {
+            "H" | "h" => Player::Human,
+            "R" | "r" => Player::Robot,
+            _ => return Err(ParseError::InvalidSide),
+        };
+
+        let rows: Vec<&str> = grid.split('/').collect();
+
+        if rows.len() != GRID_HEIGHT {
+            return Err(ParseError::InvalidRowCount(rows.len()));
+        }
+
+        let mut game = Game2048::new();
+
+        for (i, row) in rows.iter().enumerate() {
+            let values: Vec<&str> = row.split(',').collect();
+
+            if values.len() != GRID_WIDTH {
+                return Err(ParseError::InvalidRowLength(values.len()));
+            }
+
+            for (x, value) in values.iter().enumerate() {
+                let value = value
+                    .trim()
+                    .parse::<usize>()
+                    .map_err(|_| ParseError::InvalidNumber)?;
+
+                if value == 1 || !(value == 0 || value.is_power_of_two()) {
+                    return Err(ParseError::InvalidValue(value));
+                }
+
+                game.grid[(GRID_HEIGHT - i - 1) * GRID_WIDTH + x] = value;
+            }
+        }
+
+        if player == Player::Human && game.grid.iter().all(|&value| value == 0) {
+            return Err(ParseError::EmptyGrid);
+        }
+
+        game.status = Status::Running(player);
+
+        if game.possible_moves().is_empty() {
+            match player {
+                Player::Human => game.status = Status::Finished,
+                Player::Robot => return Err(ParseError::RobotCannotMove),
+            }
+        }
+
+        Ok(game)
+    }
 }
 
 impl Default for Game2048 {
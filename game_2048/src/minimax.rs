@@ -2,6 +2,9 @@ use crate::*;
 
 use std::convert::TryFrom;
 
+use ::minimax::notation::{InvalidNotation, Notation};
+use ::minimax::repl::Sides;
+use ::minimax::symmetry::{canonical_form_by_key, SquareSymmetry, Symmetric};
 use ::minimax::{
     GameResult as MinimaxGameResult, GameState, Player as MinimaxPlayer, Status as MinimaxStatus,
 };
@@ -65,3 +68,163 @@ impl GameState for Game2048 {
         self.make_move(*mov)
     }
 }
+
+fn transform_place(place: Place, symmetry: SquareSymmetry) -> Place {
+    let (x, y) = place.get_xy();
+    let (x, y) = symmetry.apply(x, y, GRID_WIDTH);
+
+    Place::from_xy(x, y).expect("Invalid place.")
+}
+
+fn transform_direction(direction: Direction, symmetry: SquareSymmetry) -> Direction {
+    let (dx, dy) = match direction {
+        Direction::Up => (0, 1),
+        Direction::Down => (0, -1),
+        Direction::Left => (-1, 0),
+        Direction::Right => (1, 0),
+    };
+
+    match symmetry.apply_vector(dx, dy) {
+        (0, 1) => Direction::Up,
+        (0, -1) => Direction::Down,
+        (-1, 0) => Direction::Left,
+        (1, 0) => Direction::Right,
+        _ => panic!("Invalid direction vector."),
+    }
+}
+
+impl Sides for Game2048 {
+    type Side = Player;
+
+    fn get_side(player: MinimaxPlayer) -> Player {
+        player.into()
+    }
+}
+
+impl Symmetric for Game2048 {
+    type Symmetry = SquareSymmetry;
+
+    fn symmetries() -> Vec<SquareSymmetry> {
+        SquareSymmetry::values().collect()
+    }
+
+    fn inverse(symmetry: SquareSymmetry) -> SquareSymmetry {
+        symmetry.inverse()
+    }
+
+    fn transform(&self, symmetry: SquareSymmetry) -> Game2048 {
+        let mut game = self.clone();
+
+        for (i, &value) in self.grid.iter().enumerate() {
+            game.set_value(transform_place(Place(i), symmetry), value);
+        }
+
+        game
+    }
+
+    fn transform_move(mov: &Move, symmetry: SquareSymmetry) -> Move {
+        match *mov {
+            Move::Human(direction) => Move::Human(transform_direction(direction, symmetry)),
+            Move::Robot(place, value) => Move::Robot(transform_place(place, symmetry), value),
+        }
+    }
+
+    fn canonical_form(&self) -> (Game2048, SquareSymmetry) {
+        canonical_form_by_key(self, |game| game.grid)
+    }
+}
+
+impl GameState for Bitboard2048 {
+    type Move = Move;
+    type MoveError = MoveError;
+
+    fn get_status(&self) -> MinimaxStatus {
+        self.get_status().into()
+    }
+
+    fn possible_moves(&self) -> Vec<Move> {
+        self.possible_moves()
+    }
+
+    fn make_move(&mut self, mov: &Move) -> Result<(), MoveError> {
+        self.make_move(*mov)
+    }
+}
+
+const PLACE_KEYS: [&str; GRID_SIZE] = [
+    "Z", "X", "C", "V", "A", "S", "D", "F", "Q", "W", "E", "R", "1", "2", "3", "4",
+];
+
+impl Sides for Bitboard2048 {
+    type Side = Player;
+
+    fn get_side(player: MinimaxPlayer) -> Player {
+        player.into()
+    }
+}
+
+impl Notation for Move {
+    fn format(&self) -> String {
+        match self {
+            Move::Human(Direction::Up) => "W".to_string(),
+            Move::Human(Direction::Left) => "A".to_string(),
+            Move::Human(Direction::Down) => "S".to_string(),
+            Move::Human(Direction::Right) => "D".to_string(),
+            Move::Robot(place, value) => format!("{},{}", PLACE_KEYS[place.0], value),
+        }
+    }
+
+    fn parse(input: &str) -> Result<Move, InvalidNotation> {
+        let input = input.trim().to_uppercase();
+        let mut input_iterator = input.split(',');
+
+        let key = input_iterator.next().map(|s| s.trim());
+        let value = input_iterator.next().map(|s| s.trim());
+
+        if input_iterator.next().is_some() {
+            return Err(InvalidNotation);
+        }
+
+        match (key, value) {
+            (Some("W"), None) => Ok(Move::Human(Direction::Up)),
+            (Some("A"), None) => Ok(Move::Human(Direction::Left)),
+            (Some("S"), None) => Ok(Move::Human(Direction::Down)),
+            (Some("D"), None) => Ok(Move::Human(Direction::Right)),
+            (Some(key), Some(value)) => {
+                let index = PLACE_KEYS
+                    .iter()
+                    .position(|&place_key| place_key == key)
+                    .ok_or(InvalidNotation)?;
+
+                let value = value.parse::<usize>().map_err(|_| InvalidNotation)?;
+
+                if ![2, 4].contains(&value) {
+                    return Err(InvalidNotation);
+                }
+
+                Ok(Move::Robot(Place(index), value))
+            }
+            _ => Err(InvalidNotation),
+        }
+    }
+}
+
+impl Notation for Game2048 {
+    fn format(&self) -> String {
+        self.to_compact_string()
+    }
+
+    fn parse(input: &str) -> Result<Game2048, InvalidNotation> {
+        input.parse().map_err(|_| InvalidNotation)
+    }
+}
+
+impl Notation for Bitboard2048 {
+    fn format(&self) -> String {
+        self.to_compact_string()
+    }
+
+    fn parse(input: &str) -> Result<Bitboard2048, InvalidNotation> {
+        input.parse().map_err(|_| InvalidNotation)
+    }
+}
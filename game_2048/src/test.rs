@@ -1,5 +1,16 @@
 use crate::*;
 
+use ::minimax::conformance::check_conformance;
+use ::minimax::notation::{InvalidNotation, Notation};
+use ::minimax::perft::{divide, perft};
+use ::minimax::random::XorShift;
+use ::minimax::symmetry::Symmetric;
+use ::minimax::transposition::TranspositionTable;
+use ::minimax::GameState;
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
 #[test]
 fn test_2048() {
     let mut game = Game2048::new();
@@ -60,10 +71,7 @@ fn test_2048() {
 
 #[test]
 fn test_finish() {
-    let mut game = Game2048 {
-        status: Status::Running(Player::Robot),
-        grid: [0, 4, 2, 4, 4, 2, 4, 2, 2, 4, 2, 4, 4, 2, 4, 2],
-    };
+    let mut game: Game2048 = "4,2,4,2/2,4,2,4/4,2,4,2/0,4,2,4:r".parse().unwrap();
 
     assert_eq!(
         game.make_move(Move::Robot(Place::from_xy(0, 0).unwrap(), 2)),
@@ -107,10 +115,7 @@ fn test_interleaved() {
 
 #[test]
 fn test_false_finish() {
-    let mut game = Game2048 {
-        status: Status::Running(Player::Robot),
-        grid: [4, 8, 2, 2, 64, 128, 4, 4, 8, 8, 16, 2, 4, 4, 2, 0],
-    };
+    let mut game: Game2048 = "4,4,2,0/8,8,16,2/64,128,4,4/4,8,2,2:r".parse().unwrap();
 
     assert_eq!(
         game.make_move(Move::Robot(Place::from_xy(3, 3).unwrap(), 4)),
@@ -119,3 +124,357 @@ fn test_false_finish() {
 
     assert_eq!(game.get_status(), Status::Running(Player::Human));
 }
+
+fn assert_same_state(game: &Game2048, bitboard: &Bitboard2048) {
+    assert_eq!(game.get_status(), bitboard.get_status());
+    assert_eq!(game.get_values(), &bitboard.get_values()[..]);
+    assert_eq!(game.possible_moves(), bitboard.possible_moves());
+    assert_eq!(game, &Game2048::from(bitboard));
+    assert_eq!(Bitboard2048::try_from(game).as_ref(), Ok(bitboard));
+}
+
+#[test]
+fn test_bitboard_random_games() {
+    let mut rng = XorShift::new(0x2048_2048_2048_2048);
+
+    for _ in 0..50 {
+        let mut game = Game2048::new();
+        let mut bitboard = Bitboard2048::new();
+
+        while let Status::Running(_) = game.get_status() {
+            assert_same_state(&game, &bitboard);
+
+            let blocked: Vec<_> = Direction::values()
+                .map(Move::Human)
+                .filter(|m| !game.possible_moves().contains(m))
+                .collect();
+
+            if !blocked.is_empty() {
+                let mov = rng.choose(&blocked);
+                assert_eq!(game.clone().make_move(mov), bitboard.clone().make_move(mov));
+            }
+
+            let mov = rng.choose(&game.possible_moves());
+
+            assert_eq!(game.make_move(mov), Ok(()));
+            assert_eq!(bitboard.make_move(mov), Ok(()));
+        }
+
+        assert_same_state(&game, &bitboard);
+    }
+}
+
+#[test]
+fn test_bitboard_tile_cap() {
+    let mut game: Game2048 = "0,0,0,0/0,0,0,0/0,0,0,0/32768,32768,0,0:h".parse().unwrap();
+    let mut bitboard = Bitboard2048::try_from(&game).unwrap();
+
+    assert_eq!(Bitboard2048::MAX_TILE, 32768);
+    assert_eq!(bitboard.get_value(Place::from_xy(1, 0).unwrap()), 32768);
+
+    assert_eq!(game.make_move(Move::Human(Direction::Left)), Ok(()));
+    assert_eq!(game.get_value(Place::from_xy(0, 0).unwrap()), 65536);
+
+    assert_eq!(
+        bitboard.make_move(Move::Human(Direction::Left)),
+        Err(MoveError::DirectionBlocked(Direction::Left))
+    );
+
+    assert_eq!(Bitboard2048::try_from(&game), Err(TileTooLarge(65536)));
+}
+
+#[test]
+fn test_bitboard_moves() {
+    let mut game = Game2048::new();
+    let mut bitboard = Bitboard2048::new();
+
+    let moves = [
+        Move::Robot(Place::from_xy(0, 0).unwrap(), 2),
+        Move::Human(Direction::Right),
+        Move::Robot(Place::from_xy(1, 0).unwrap(), 2),
+        Move::Human(Direction::Up),
+        Move::Robot(Place::from_xy(3, 0).unwrap(), 4),
+        Move::Human(Direction::Up),
+        Move::Robot(Place::from_xy(0, 3).unwrap(), 4),
+        Move::Human(Direction::Left),
+        Move::Robot(Place::from_xy(3, 0).unwrap(), 1),
+        Move::Robot(Place::from_xy(0, 3).unwrap(), 2),
+        Move::Human(Direction::Left),
+        Move::Robot(Place::from_xy(3, 0).unwrap(), 2),
+        Move::Human(Direction::Left),
+    ];
+
+    for &mov in moves.iter() {
+        assert_eq!(game.make_move(mov), bitboard.make_move(mov));
+        assert_same_state(&game, &bitboard);
+    }
+
+    assert_eq!(bitboard.get_value(Place::from_xy(0, 3).unwrap()), 8);
+    assert_eq!(bitboard.get_value(Place::from_xy(0, 2).unwrap()), 4);
+}
+
+#[test]
+fn test_symmetric_minimax() {
+    let game = Game2048 {
+        status: Status::Running(Player::Human),
+        grid: [2, 4, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0],
+    };
+
+    let minimax = game.minimax(2).unwrap();
+    let moves: HashSet<Move> = minimax.moves.iter().cloned().collect();
+
+    for symmetry in Game2048::symmetries() {
+        let transformed = game.transform(symmetry);
+
+        assert_eq!(transformed.get_values().iter().sum::<usize>(), 16);
+        assert_eq!(
+            transformed
+                .possible_moves()
+                .into_iter()
+                .collect::<HashSet<_>>(),
+            game.possible_moves()
+                .iter()
+                .map(|mov| Game2048::transform_move(mov, symmetry))
+                .collect()
+        );
+    }
+
+    let mut table = TranspositionTable::new();
+    let symmetric = table.minimax_symmetric(&game, 2).unwrap();
+
+    assert_eq!(symmetric.outcome, minimax.outcome);
+    assert_eq!(symmetric.moves.into_iter().collect::<HashSet<_>>(), moves);
+    assert!(table.get_hits() > 0);
+}
+
+#[test]
+fn test_notation() {
+    let mut moves: Vec<Move> = (0..4)
+        .flat_map(|y| (0..4).map(move |x| Place::from_xy(x, y).unwrap()))
+        .flat_map(|place| vec![Move::Robot(place, 2), Move::Robot(place, 4)])
+        .collect();
+
+    moves.extend(Direction::values().map(Move::Human));
+
+    assert_eq!(moves.len(), 36);
+
+    for mov in moves.iter() {
+        assert_eq!(Move::parse(&mov.format()), Ok(*mov));
+    }
+
+    let keys: HashSet<String> = moves.iter().map(Notation::format).collect();
+    assert_eq!(keys.len(), 36);
+
+    let place = |x, y| Place::from_xy(x, y).unwrap();
+
+    assert_eq!(Move::Human(Direction::Left).format(), "A");
+    assert_eq!(Move::Robot(place(0, 0), 2).format(), "Z,2");
+    assert_eq!(Move::Robot(place(3, 3), 4).format(), "4,4");
+    assert_eq!(Move::parse("d"), Ok(Move::Human(Direction::Right)));
+    assert_eq!(Move::parse("r,4"), Ok(Move::Robot(place(3, 2), 4)));
+    assert_eq!(Move::parse(" 1 , 2 "), Ok(Move::Robot(place(0, 3), 2)));
+    assert_eq!(Move::parse("V, 4"), Ok(Move::Robot(place(3, 0), 4)));
+    assert_eq!(Move::parse("V, 8"), Err(InvalidNotation));
+    assert_eq!(Move::parse("V, 0"), Err(InvalidNotation));
+    assert_eq!(Move::parse("Z"), Err(InvalidNotation));
+    assert_eq!(Move::parse("W, two"), Err(InvalidNotation));
+    assert_eq!(Move::parse("5, 2"), Err(InvalidNotation));
+    assert_eq!(Move::parse("Q, 2, 4"), Err(InvalidNotation));
+    assert_eq!(Move::parse(""), Err(InvalidNotation));
+}
+
+#[test]
+fn test_state_notation() {
+    let mut game = Game2048::new();
+
+    assert_eq!(game.format(), "0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0:r");
+    assert_eq!(Game2048::parse(&game.format()), Ok(game.clone()));
+
+    let place = |x, y| Place::from_xy(x, y).unwrap();
+
+    assert_eq!(game.make_move(Move::Robot(place(0, 3), 2)), Ok(()));
+    assert_eq!(game.make_move(Move::Human(Direction::Down)), Ok(()));
+    assert_eq!(game.make_move(Move::Robot(place(3, 0), 4)), Ok(()));
+
+    assert_eq!(game.format(), "0,0,0,0/0,0,0,0/0,0,0,0/2,0,0,4:h");
+    assert_eq!(Game2048::parse(&game.format()), Ok(game.clone()));
+
+    let bitboard = Bitboard2048::try_from(&game).unwrap();
+
+    assert_eq!(bitboard.format(), game.format());
+    assert_eq!(Bitboard2048::parse(&game.format()), Ok(bitboard));
+
+    let full = "2,4,8,16/4,8,16,32/8,16,32,64/16,32,64,128";
+    let finished = Game2048::parse(&format!("{}:h", full)).unwrap();
+
+    assert_eq!(finished.get_status(), Status::Finished);
+    assert_eq!(finished.format(), format!("{}:h", full));
+
+    assert_eq!(
+        Game2048::parse(&format!("{}:r", full)),
+        Err(InvalidNotation)
+    );
+    assert_eq!(
+        Game2048::parse("0,0,0,0/0,0,0,0/0,0,0,0:r"),
+        Err(InvalidNotation)
+    );
+    assert_eq!(
+        Game2048::parse("0,0,0,0/0,0,0,0/0,0,0,0/0,0,0:r"),
+        Err(InvalidNotation)
+    );
+    assert_eq!(
+        Game2048::parse("0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,3:r"),
+        Err(InvalidNotation)
+    );
+    assert_eq!(
+        Game2048::parse("0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,1:r"),
+        Err(InvalidNotation)
+    );
+    assert_eq!(
+        Bitboard2048::parse("0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,65536:r"),
+        Err(InvalidNotation)
+    );
+    assert_eq!(
+        Game2048::parse("0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0:x"),
+        Err(InvalidNotation)
+    );
+    assert_eq!(
+        Game2048::parse("0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0"),
+        Err(InvalidNotation)
+    );
+}
+
+#[test]
+fn test_from_str() {
+    let game: Game2048 = "0,0,0,0/0,0,2,0/0,0,0,0/4,0,0,0:h".parse().unwrap();
+
+    assert_eq!(game.get_status(), Status::Running(Player::Human));
+    assert_eq!(game.get_value(Place::from_xy(2, 2).unwrap()), 2);
+    assert_eq!(game.get_value(Place::from_xy(0, 0).unwrap()), 4);
+    assert_eq!(game.get_values().iter().sum::<usize>(), 6);
+
+    let large: Game2048 = "0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,65536:r".parse().unwrap();
+
+    assert_eq!(large.get_value(Place::from_xy(3, 0).unwrap()), 65536);
+    assert_eq!(
+        "0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,65536:r".parse::<Bitboard2048>(),
+        Err(ParseError::InvalidValue(65536))
+    );
+
+    assert_eq!(
+        game.to_compact_string(),
+        "0,0,0,0/0,0,2,0/0,0,0,0/4,0,0,0:h"
+    );
+    assert_eq!(large.to_compact_string().parse(), Ok(large));
+
+    let mut game = Game2048::new();
+
+    while let Status::Running(_) = game.get_status() {
+        let bitboard: Bitboard2048 = game.to_compact_string().parse().unwrap();
+
+        assert_eq!(game.to_compact_string().parse().as_ref(), Ok(&game));
+        assert_eq!(bitboard.to_compact_string(), game.to_compact_string());
+
+        let moves = game.possible_moves();
+        assert_eq!(game.make_move(moves[moves.len() / 2]), Ok(()));
+    }
+
+    assert_eq!(game.to_compact_string().parse(), Ok(game));
+
+    let errors = [
+        ("0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0", ParseError::MissingSide),
+        ("0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0:x", ParseError::InvalidSide),
+        ("0,0,0,0/0,0,0,0/0,0,0,0:r", ParseError::InvalidRowCount(3)),
+        (
+            "0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0:r",
+            ParseError::InvalidRowCount(5),
+        ),
+        (
+            "0,0,0,0/0,0,0/0,0,0,0/0,0,0,0:r",
+            ParseError::InvalidRowLength(3),
+        ),
+        (
+            "0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,x:r",
+            ParseError::InvalidNumber,
+        ),
+        (
+            "0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,-2:r",
+            ParseError::InvalidNumber,
+        ),
+        (
+            "0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,1:r",
+            ParseError::InvalidValue(1),
+        ),
+        (
+            "0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,6:r",
+            ParseError::InvalidValue(6),
+        ),
+        ("0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0:h", ParseError::EmptyGrid),
+        (
+            "2,4,2,4/4,2,4,2/2,4,2,4/4,2,4,2:r",
+            ParseError::RobotCannotMove,
+        ),
+    ];
+
+    for &(input, error) in errors.iter() {
+        assert_eq!(input.parse::<Game2048>(), Err(error));
+    }
+}
+
+#[test]
+fn test_conformance() {
+    let report = check_conformance(&Game2048::new(), 20, 10_000, 0x2048).unwrap();
+
+    assert_eq!(report.games, 20);
+    assert!(report.moves > 20 * 20);
+
+    let bitboard_report = check_conformance(&Bitboard2048::new(), 20, 10_000, 0x2048).unwrap();
+
+    assert_eq!(bitboard_report, report);
+
+    let game: Game2048 = "4,2,4,2/2,4,2,4/4,2,4,2/0,4,2,4:r".parse().unwrap();
+    let report = check_conformance(&game, 10, 10_000, 1).unwrap();
+
+    assert_eq!(report.games, 10);
+    assert!(report.moves >= 10);
+    assert_eq!(report.states, report.moves + report.games);
+}
+
+#[test]
+fn test_perft() {
+    let game = Game2048::new();
+
+    let leaves: Vec<usize> = (0..=3)
+        .map(|depth| perft(&game, depth).unwrap().leaves)
+        .collect();
+
+    assert_eq!(leaves, vec![1, 32, 96, 2880]);
+
+    for depth in 0..=3 {
+        assert_eq!(perft(&Bitboard2048::new(), depth), perft(&game, depth));
+    }
+
+    let moves = divide(&game, 2).unwrap();
+
+    assert_eq!(moves.len(), 32);
+
+    for (mov, count) in moves.iter() {
+        let expected = match mov {
+            Move::Robot(place, _value) => match place.get_xy() {
+                (0, 0) | (0, 3) | (3, 0) | (3, 3) => 2,
+                (1, 1) | (1, 2) | (2, 1) | (2, 2) => 4,
+                _ => 3,
+            },
+            Move::Human(_) => panic!("Unexpected human move {:?}.", mov),
+        };
+
+        assert_eq!(count.leaves, expected);
+        assert_eq!(count.finished, 0);
+    }
+
+    let game: Game2048 = "4,2,4,2/2,4,2,4/4,2,4,2/0,4,2,4:r".parse().unwrap();
+    let count = perft(&game, 2).unwrap();
+
+    assert_eq!(count.finished, 1);
+    assert!(count.leaves > count.finished);
+}
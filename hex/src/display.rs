@@ -0,0 +1,48 @@
+use crate::*;
+
+use std::fmt;
+
+fn get_char(player: Option<Player>) -> char {
+    match player {
+        None => '.',
+        Some(Player::Red) => 'R',
+        Some(Player::Blue) => 'B',
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", (b'A' + self.x as u8) as char, self.y + 1)
+    }
+}
+
+impl fmt::Display for Player {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Player::Red => write!(f, "Red"),
+            Player::Blue => write!(f, "Blue"),
+        }
+    }
+}
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = self.get_size().to_string().len();
+
+        let letters: Vec<String> = (0..self.get_size())
+            .map(|x| ((b'A' + x as u8) as char).to_string())
+            .collect();
+
+        writeln!(f, "{:1$} {2}", "", digits, letters.join(" "))?;
+
+        for y in 0..self.get_size() {
+            let cells: Vec<String> = (0..self.get_size())
+                .map(|x| get_char(self.get_cell(Cell::new(x, y))).to_string())
+                .collect();
+
+            writeln!(f, "{:1$}{2:>3$} {4}", "", y, y + 1, digits, cells.join(" "))?;
+        }
+
+        Ok(())
+    }
+}
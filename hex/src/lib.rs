@@ -0,0 +1,360 @@
+mod display;
+mod minimax;
+mod union_find;
+
+#[cfg(test)]
+mod test;
+
+use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
+
+pub use crate::union_find::UnionFind;
+
+const NEIGHBORS: [(isize, isize); 6] = [(1, 0), (-1, 0), (0, 1), (0, -1), (1, -1), (-1, 1)];
+const EDGE_COUNT: usize = 4;
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Cell {
+    pub x: usize,
+    pub y: usize,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Move {
+    Place(Cell),
+    Swap,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Player {
+    Red,
+    Blue,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Status {
+    Running(Player),
+    Finished(Player),
+}
+
+#[derive(Clone, Debug)]
+pub struct Hex {
+    size: usize,
+    swap_rule: bool,
+    status: Status,
+    grid: Vec<Option<Player>>,
+    groups: UnionFind,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum MoveError {
+    InvalidStatus(Status),
+    WrongPlayer(Player),
+    CellOutOfBounds(Cell),
+    CellAlreadyUsed(Cell, Player),
+    SwapNotAllowed,
+}
+
+impl Cell {
+    pub fn new(x: usize, y: usize) -> Cell {
+        Cell { x, y }
+    }
+}
+
+impl Player {
+    pub fn other(self) -> Player {
+        match self {
+            Player::Red => Player::Blue,
+            Player::Blue => Player::Red,
+        }
+    }
+}
+
+impl Hex {
+    pub fn new(size: usize) -> Hex {
+        Hex::with_swap_rule(size, false)
+    }
+
+    pub fn with_swap_rule(size: usize, swap_rule: bool) -> Hex {
+        if size == 0 {
+            panic!("The size of the board must be positive.");
+        }
+
+        Hex {
+            size,
+            swap_rule,
+            status: Status::Running(Player::Red),
+            grid: vec![None; size * size],
+            groups: UnionFind::new(size * size + EDGE_COUNT),
+        }
+    }
+
+    pub fn get_size(&self) -> usize {
+        self.size
+    }
+
+    pub fn has_swap_rule(&self) -> bool {
+        self.swap_rule
+    }
+
+    pub fn get_status(&self) -> Status {
+        self.status
+    }
+
+    fn get_grid_index(&self, cell: Cell) -> Option<usize> {
+        if cell.x < self.size && cell.y < self.size {
+            Some(cell.y * self.size + cell.x)
+        } else {
+            None
+        }
+    }
+
+    fn get_grid_cell(&self, index: usize) -> Cell {
+        Cell::new(index % self.size, index / self.size)
+    }
+
+    pub fn get_cell(&self, cell: Cell) -> Option<Player> {
+        self.get_grid_index(cell).and_then(|i| self.grid[i])
+    }
+
+    fn get_neighbors(&self, cell: Cell) -> impl Iterator<Item = Cell> + '_ {
+        NEIGHBORS.iter().filter_map(move |&(dx, dy)| {
+            let x = cell.x as isize + dx;
+            let y = cell.y as isize + dy;
+
+            if x < 0 || y < 0 || x as usize >= self.size || y as usize >= self.size {
+                None
+            } else {
+                Some(Cell::new(x as usize, y as usize))
+            }
+        })
+    }
+
+    fn get_edges(&self, player: Player) -> (usize, usize) {
+        let first = self.size * self.size;
+
+        match player {
+            Player::Red => (first, first + 1),
+            Player::Blue => (first + 2, first + 3),
+        }
+    }
+
+    fn get_touched_edge(&self, player: Player, cell: Cell) -> Option<usize> {
+        let (start, end) = self.get_edges(player);
+
+        let position = match player {
+            Player::Red => cell.y,
+            Player::Blue => cell.x,
+        };
+
+        if position == 0 {
+            Some(start)
+        } else if position == self.size - 1 {
+            Some(end)
+        } else {
+            None
+        }
+    }
+
+    fn can_swap(&self) -> bool {
+        self.swap_rule
+            && self.status == Status::Running(Player::Blue)
+            && self.grid.iter().filter(|p| p.is_some()).count() == 1
+    }
+
+    pub fn possible_moves(&self) -> Vec<Move> {
+        match self.status {
+            Status::Running(_) => {
+                let mut moves: Vec<Move> = self
+                    .grid
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, p)| p.is_none())
+                    .map(|(i, _)| Move::Place(self.get_grid_cell(i)))
+                    .collect();
+
+                if self.can_swap() {
+                    moves.push(Move::Swap);
+                }
+
+                moves
+            }
+            Status::Finished(_) => Vec::new(),
+        }
+    }
+
+    fn place_stone(&mut self, player: Player, cell: Cell) {
+        let index = self.get_grid_index(cell).expect("Invalid cell.");
+        self.grid[index] = Some(player);
+
+        if self.size == 1 {
+            let (start, end) = self.get_edges(player);
+            self.groups.union(index, start);
+            self.groups.union(index, end);
+        } else if let Some(edge) = self.get_touched_edge(player, cell) {
+            self.groups.union(index, edge);
+        }
+
+        let neighbors: Vec<usize> = self
+            .get_neighbors(cell)
+            .filter(|&neighbor| self.get_cell(neighbor) == Some(player))
+            .filter_map(|neighbor| self.get_grid_index(neighbor))
+            .collect();
+
+        for neighbor in neighbors {
+            self.groups.union(index, neighbor);
+        }
+    }
+
+    fn swap(&mut self) {
+        let index = self
+            .grid
+            .iter()
+            .position(|p| p.is_some())
+            .expect("No stone to swap.");
+
+        let cell = self.get_grid_cell(index);
+
+        self.grid[index] = None;
+        self.groups = UnionFind::new(self.size * self.size + EDGE_COUNT);
+        self.place_stone(Player::Blue, Cell::new(cell.y, cell.x));
+    }
+
+    fn is_connected(&mut self, player: Player) -> bool {
+        let (start, end) = self.get_edges(player);
+        self.groups.is_connected(start, end)
+    }
+
+    pub fn make_move(&mut self, player: Player, mov: Move) -> Result<(), MoveError> {
+        match self.status {
+            Status::Finished(_) => Err(MoveError::InvalidStatus(self.status)),
+            Status::Running(status_player) => {
+                match mov {
+                    Move::Place(cell) => {
+                        if self.get_grid_index(cell).is_none() {
+                            return Err(MoveError::CellOutOfBounds(cell));
+                        } else if let Some(cell_player) = self.get_cell(cell) {
+                            return Err(MoveError::CellAlreadyUsed(cell, cell_player));
+                        }
+                    }
+                    Move::Swap => {
+                        if !self.can_swap() {
+                            return Err(MoveError::SwapNotAllowed);
+                        }
+                    }
+                }
+
+                if status_player != player {
+                    return Err(MoveError::WrongPlayer(status_player));
+                }
+
+                match mov {
+                    Move::Place(cell) => self.place_stone(player, cell),
+                    Move::Swap => self.swap(),
+                }
+
+                self.status = if self.is_connected(player) {
+                    Status::Finished(player)
+                } else {
+                    Status::Running(player.other())
+                };
+
+                Ok(())
+            }
+        }
+    }
+
+    pub fn get_distance(&self, player: Player) -> Option<usize> {
+        let mut distances = vec![None; self.grid.len()];
+        let mut queue = VecDeque::new();
+
+        for i in 0..self.size {
+            let cell = match player {
+                Player::Red => Cell::new(i, 0),
+                Player::Blue => Cell::new(0, i),
+            };
+
+            let index = self.get_grid_index(cell).expect("Invalid cell.");
+
+            match self.grid[index] {
+                Some(p) if p == player => {
+                    distances[index] = Some(0);
+                    queue.push_front(cell);
+                }
+                None => {
+                    distances[index] = Some(1);
+                    queue.push_back(cell);
+                }
+                Some(_) => (),
+            }
+        }
+
+        while let Some(cell) = queue.pop_front() {
+            let index = self.get_grid_index(cell).expect("Invalid cell.");
+            let distance = distances[index].expect("Unvisited cell.");
+
+            for neighbor in self.get_neighbors(cell) {
+                let neighbor_index = self.get_grid_index(neighbor).expect("Invalid cell.");
+
+                let cost = match self.grid[neighbor_index] {
+                    Some(p) if p == player => 0,
+                    None => 1,
+                    Some(_) => continue,
+                };
+
+                let improved = match distances[neighbor_index] {
+                    Some(d) => distance + cost < d,
+                    None => true,
+                };
+
+                if improved {
+                    distances[neighbor_index] = Some(distance + cost);
+
+                    if cost == 0 {
+                        queue.push_front(neighbor);
+                    } else {
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+
+        (0..self.size)
+            .filter_map(|i| {
+                let cell = match player {
+                    Player::Red => Cell::new(i, self.size - 1),
+                    Player::Blue => Cell::new(self.size - 1, i),
+                };
+
+                distances[self.get_grid_index(cell).expect("Invalid cell.")]
+            })
+            .min()
+    }
+
+    pub fn get_distance_score(&self) -> isize {
+        let red = self.get_distance(Player::Red).map_or(0, |d| d as isize);
+        let blue = self.get_distance(Player::Blue).map_or(0, |d| d as isize);
+
+        blue - red
+    }
+}
+
+impl PartialEq for Hex {
+    fn eq(&self, other: &Hex) -> bool {
+        self.size == other.size
+            && self.swap_rule == other.swap_rule
+            && self.status == other.status
+            && self.grid == other.grid
+    }
+}
+
+impl Eq for Hex {}
+
+impl Hash for Hex {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.size.hash(state);
+        self.swap_rule.hash(state);
+        self.status.hash(state);
+        self.grid.hash(state);
+    }
+}
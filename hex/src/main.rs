@@ -0,0 +1,160 @@
+use ::minimax::{GameState, MinimaxResult};
+
+use std::env;
+use std::io;
+
+use hex::*;
+
+use hex::MoveError::*;
+use hex::Status::*;
+
+enum HexError {
+    MoveError(MoveError),
+    InvalidInput(String),
+}
+
+impl From<MoveError> for HexError {
+    fn from(error: MoveError) -> HexError {
+        HexError::MoveError(error)
+    }
+}
+
+fn print_instructions(game: &Hex) {
+    println!("-----------------------------");
+    println!("             HEX             ");
+    println!("-----------------------------");
+    println!();
+    println!("Red connects the top and bottom edges. Blue connects the left and right edges.");
+    println!("Enter the column letter and the row number (e.g. B3) and ENTER to place a stone.");
+
+    if game.has_swap_rule() {
+        println!("Blue can enter SWAP on the first move to take Red's opening stone.");
+    }
+
+    println!();
+}
+
+fn format_move(mov: Move) -> String {
+    match mov {
+        Move::Place(cell) => cell.to_string(),
+        Move::Swap => "SWAP".to_string(),
+    }
+}
+
+fn print_minimax(game: &Hex) -> MinimaxResult<Hex> {
+    const MINIMAX_DEPTH: usize = 2;
+
+    print!("Minimax: ");
+
+    let minimax = match game.minimax(MINIMAX_DEPTH) {
+        Ok(minimax) => {
+            println!("{:?}", minimax.outcome);
+            minimax
+        }
+        Err(error) => {
+            println!("{:?}\n", error);
+            return Err(error);
+        }
+    };
+
+    let moves: Vec<String> = minimax.moves.iter().map(|&mov| format_move(mov)).collect();
+
+    println!("{}\n", moves.join(", "));
+    Ok(minimax)
+}
+
+fn parse_input(input: String) -> Result<Move, HexError> {
+    if input == "SWAP" {
+        return Ok(Move::Swap);
+    }
+
+    let mut chars = input.chars();
+
+    let x = match chars.next() {
+        Some(c @ 'A'..='Z') => c as usize - 'A' as usize,
+        _ => return Err(HexError::InvalidInput(input)),
+    };
+
+    match chars.as_str().parse::<usize>() {
+        Ok(y) if y > 0 => Ok(Move::Place(Cell::new(x, y - 1))),
+        _ => Err(HexError::InvalidInput(input)),
+    }
+}
+
+fn handle_turn(game: &mut Hex, player: Player) -> Result<(), HexError> {
+    let input = {
+        let mut buffer = String::new();
+
+        io::stdin()
+            .read_line(&mut buffer)
+            .expect("It was not possible to read user input.");
+
+        println!();
+
+        buffer.trim().to_uppercase()
+    };
+
+    let mov = parse_input(input)?;
+
+    game.make_move(player, mov)?;
+    Ok(())
+}
+
+fn handle_error(error: HexError) {
+    match error {
+        HexError::MoveError(InvalidStatus(_)) => {
+            panic!("Cannot make any move now because the status of the game doesn't allow it.");
+        }
+        HexError::MoveError(WrongPlayer(_)) => {
+            panic!("Cannot make that move because it's not the player's turn.");
+        }
+        HexError::MoveError(CellOutOfBounds(_)) => {
+            println!("Cannot make that move because that cell is outside the board.");
+        }
+        HexError::MoveError(CellAlreadyUsed(_, _)) => {
+            println!("Cannot make that move because that cell is already used.");
+        }
+        HexError::MoveError(SwapNotAllowed) => {
+            println!("Cannot swap now. Swapping is only allowed as Blue's first move.");
+        }
+        HexError::InvalidInput(input) => {
+            println!("Invalid input: {}", input);
+        }
+    }
+
+    println!();
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let swap_rule = args.iter().any(|arg| arg == "--swap");
+
+    let size = args
+        .iter()
+        .filter_map(|arg| arg.parse().ok())
+        .find(|&size| size > 0 && size <= 26)
+        .unwrap_or(7);
+
+    let mut game = Hex::with_swap_rule(size, swap_rule);
+
+    print_instructions(&game);
+    println!("{}", game);
+
+    while let Running(player) = game.get_status() {
+        println!("{} to move.", player);
+        print_minimax(&game).ok();
+
+        let result = handle_turn(&mut game, player);
+
+        if let Err(error) = result {
+            handle_error(error);
+        }
+
+        println!("{}", game);
+    }
+
+    if let Finished(winner) = game.get_status() {
+        println!("{} wins.", winner);
+    }
+}
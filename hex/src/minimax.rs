@@ -0,0 +1,58 @@
+use crate::*;
+
+use ::minimax::{
+    GameResult as MinimaxGameResult, GameState, Player as MinimaxPlayer, Status as MinimaxStatus,
+};
+
+impl From<Player> for MinimaxPlayer {
+    fn from(player: Player) -> MinimaxPlayer {
+        match player {
+            Player::Red => MinimaxPlayer::One,
+            Player::Blue => MinimaxPlayer::Two,
+        }
+    }
+}
+
+impl From<MinimaxPlayer> for Player {
+    fn from(player: MinimaxPlayer) -> Player {
+        match player {
+            MinimaxPlayer::One => Player::Red,
+            MinimaxPlayer::Two => Player::Blue,
+        }
+    }
+}
+
+impl From<Status> for MinimaxStatus {
+    fn from(status: Status) -> MinimaxStatus {
+        match status {
+            Status::Running(player) => MinimaxStatus::Running(player.into()),
+            Status::Finished(winner) => {
+                MinimaxStatus::Finished(MinimaxGameResult::Win(winner.into()))
+            }
+        }
+    }
+}
+
+impl GameState for Hex {
+    type Move = Move;
+    type MoveError = MoveError;
+
+    fn get_status(&self) -> MinimaxStatus {
+        self.get_status().into()
+    }
+
+    fn possible_moves(&self) -> Vec<Move> {
+        self.possible_moves()
+    }
+
+    fn make_move(&mut self, mov: &Move) -> Result<(), MoveError> {
+        match self.status {
+            Status::Finished(_) => Err(MoveError::InvalidStatus(self.status)),
+            Status::Running(player) => self.make_move(player, *mov),
+        }
+    }
+
+    fn get_score(&self) -> isize {
+        self.get_distance_score()
+    }
+}
@@ -0,0 +1,132 @@
+use crate::MoveError::*;
+use crate::Status::*;
+use crate::*;
+
+use ::minimax::random::XorShift;
+use ::minimax::Outcome::*;
+use ::minimax::{GameResult as MinimaxGameResult, GameState};
+
+fn place(x: usize, y: usize) -> Move {
+    Move::Place(Cell::new(x, y))
+}
+
+#[test]
+fn test_hex() {
+    let mut game = Hex::new(3);
+
+    assert_eq!(game.possible_moves().len(), 9);
+    assert_eq!(game.get_distance_score(), 0);
+
+    assert_eq!(
+        game.make_move(Player::Red, place(3, 0)),
+        Err(CellOutOfBounds(Cell::new(3, 0)))
+    );
+
+    assert_eq!(game.make_move(Player::Red, Move::Swap), Err(SwapNotAllowed));
+    assert_eq!(game.make_move(Player::Red, place(0, 0)), Ok(()));
+
+    assert_eq!(
+        game.make_move(Player::Blue, place(0, 0)),
+        Err(CellAlreadyUsed(Cell::new(0, 0), Player::Red))
+    );
+
+    assert_eq!(
+        game.make_move(Player::Red, place(0, 1)),
+        Err(WrongPlayer(Player::Blue))
+    );
+
+    assert_eq!(game.make_move(Player::Blue, place(2, 0)), Ok(()));
+    assert_eq!(game.make_move(Player::Red, place(0, 1)), Ok(()));
+    assert_eq!(game.make_move(Player::Blue, place(2, 1)), Ok(()));
+    assert_eq!(game.get_distance(Player::Red), Some(1));
+    assert_eq!(game.get_distance(Player::Blue), Some(2));
+
+    assert_eq!(game.make_move(Player::Red, place(0, 2)), Ok(()));
+    assert_eq!(game.get_status(), Finished(Player::Red));
+    assert_eq!(game.get_distance(Player::Red), Some(0));
+    assert_eq!(game.get_distance(Player::Blue), None);
+
+    assert_eq!(
+        game.make_move(Player::Blue, place(2, 2)),
+        Err(InvalidStatus(Finished(Player::Red)))
+    );
+}
+
+#[test]
+fn test_diagonal_connection() {
+    let mut game = Hex::new(3);
+
+    for &mov in [place(2, 0), place(0, 0), place(1, 1), place(0, 1)].iter() {
+        assert_eq!(GameState::make_move(&mut game, &mov), Ok(()));
+    }
+
+    assert_eq!(game.make_move(Player::Red, place(0, 2)), Ok(()));
+    assert_eq!(game.get_status(), Finished(Player::Red));
+
+    let mut game = Hex::new(3);
+
+    for &mov in [place(0, 0), place(2, 0), place(1, 1), place(2, 1)].iter() {
+        assert_eq!(GameState::make_move(&mut game, &mov), Ok(()));
+    }
+
+    assert_eq!(game.make_move(Player::Red, place(2, 2)), Ok(()));
+    assert_eq!(game.get_status(), Running(Player::Blue));
+    assert_eq!(game.get_distance(Player::Red), Some(2));
+}
+
+#[test]
+fn test_swap() {
+    let mut game = Hex::with_swap_rule(3, true);
+
+    assert_eq!(game.make_move(Player::Red, Move::Swap), Err(SwapNotAllowed));
+    assert_eq!(game.make_move(Player::Red, place(1, 0)), Ok(()));
+    assert_eq!(game.possible_moves().last(), Some(&Move::Swap));
+
+    assert_eq!(game.make_move(Player::Blue, Move::Swap), Ok(()));
+    assert_eq!(game.get_cell(Cell::new(1, 0)), None);
+    assert_eq!(game.get_cell(Cell::new(0, 1)), Some(Player::Blue));
+    assert_eq!(game.get_status(), Running(Player::Red));
+    assert!(!game.possible_moves().contains(&Move::Swap));
+
+    assert_eq!(game.make_move(Player::Red, place(1, 1)), Ok(()));
+    assert_eq!(
+        game.make_move(Player::Blue, Move::Swap),
+        Err(SwapNotAllowed)
+    );
+}
+
+#[test]
+fn test_no_draws() {
+    let mut rng = XorShift::new(0x4845_5848_4558_4845);
+
+    for _ in 0..50 {
+        let mut game = Hex::new(6);
+
+        while let Running(player) = game.get_status() {
+            let mov = rng.choose(&game.possible_moves());
+            assert_eq!(game.make_move(player, mov), Ok(()));
+        }
+
+        match game.get_status() {
+            Finished(winner) => {
+                assert_eq!(game.get_distance(winner), Some(0));
+                assert_ne!(game.get_distance(winner.other()), Some(0));
+            }
+            Running(_) => unreachable!(),
+        }
+    }
+}
+
+#[test]
+fn test_minimax() {
+    let game = Hex::new(2);
+
+    let minimax = game.minimax(4).unwrap();
+
+    match minimax.outcome {
+        Definite(MinimaxGameResult::Win(player), _) => assert_eq!(player, Player::Red.into()),
+        outcome => panic!("Unexpected outcome {:?}.", outcome),
+    }
+
+    assert_eq!(minimax.moves, vec![place(1, 0), place(0, 1)]);
+}
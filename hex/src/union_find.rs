@@ -0,0 +1,54 @@
+#[derive(Clone, Debug)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    ranks: Vec<usize>,
+}
+
+impl UnionFind {
+    pub fn new(size: usize) -> UnionFind {
+        UnionFind {
+            parents: (0..size).collect(),
+            ranks: vec![0; size],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    pub fn find(&mut self, mut element: usize) -> usize {
+        while self.parents[element] != element {
+            let grandparent = self.parents[self.parents[element]];
+            self.parents[element] = grandparent;
+            element = grandparent;
+        }
+
+        element
+    }
+
+    pub fn union(&mut self, lhs: usize, rhs: usize) {
+        let lhs = self.find(lhs);
+        let rhs = self.find(rhs);
+
+        if lhs == rhs {
+            return;
+        }
+
+        if self.ranks[lhs] < self.ranks[rhs] {
+            self.parents[lhs] = rhs;
+        } else if self.ranks[lhs] > self.ranks[rhs] {
+            self.parents[rhs] = lhs;
+        } else {
+            self.parents[rhs] = lhs;
+            self.ranks[lhs] += 1;
+        }
+    }
+
+    pub fn is_connected(&mut self, lhs: usize, rhs: usize) -> bool {
+        self.find(lhs) == self.find(rhs)
+    }
+}
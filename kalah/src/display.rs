@@ -0,0 +1,60 @@
+use crate::*;
+
+use std::fmt;
+
+impl fmt::Display for Player {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Player::South => write!(f, "South"),
+            Player::North => write!(f, "North"),
+        }
+    }
+}
+
+fn format_row(numbers: impl Iterator<Item = usize>, brackets: bool) -> String {
+    numbers
+        .map(|n| {
+            if brackets {
+                format!("[{:>2}]", n)
+            } else {
+                format!(" {:>2} ", n)
+            }
+        })
+        .collect()
+}
+
+impl fmt::Display for Kalah {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut north_pits: Vec<usize> = Pit::values()
+            .map(|pit| self.get_seeds(Player::North, pit))
+            .collect();
+        north_pits.reverse();
+
+        let south_pits = Pit::values().map(|pit| self.get_seeds(Player::South, pit));
+        let blank = " ".repeat(4 * PIT_COUNT);
+
+        writeln!(f, "     {}", format_row((1..=PIT_COUNT).rev(), false))?;
+        writeln!(
+            f,
+            "     {}  {}",
+            format_row(north_pits.into_iter(), true),
+            Player::North
+        )?;
+        writeln!(
+            f,
+            "[{:>2}] {} [{:>2}]",
+            self.get_store(Player::North),
+            blank,
+            self.get_store(Player::South)
+        )?;
+        writeln!(
+            f,
+            "     {}  {}",
+            format_row(south_pits, true),
+            Player::South
+        )?;
+        writeln!(f, "     {}", format_row(1..=PIT_COUNT, false))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+mod display;
+mod minimax;
+
+#[cfg(test)]
+mod test;
+
+const PIT_COUNT: usize = 6;
+const DEFAULT_SEEDS: usize = 4;
+const BOARD_SIZE: usize = 2 * (PIT_COUNT + 1);
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Pit(usize);
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct InvalidPit;
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Player {
+    South,
+    North,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum GameResult {
+    Draw,
+    Win(Player),
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Status {
+    Running(Player),
+    Finished(GameResult),
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Kalah {
+    status: Status,
+    board: [usize; BOARD_SIZE],
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum MoveError {
+    InvalidStatus(Status),
+    WrongPlayer(Player),
+    EmptyPit(Pit),
+}
+
+impl Pit {
+    pub fn new(index: usize) -> Result<Pit, InvalidPit> {
+        if index >= PIT_COUNT {
+            Err(InvalidPit)
+        } else {
+            Ok(Pit(index))
+        }
+    }
+
+    pub fn get_index(self) -> usize {
+        self.0
+    }
+
+    pub fn values() -> impl Iterator<Item = Pit> {
+        (0..PIT_COUNT).map(Pit)
+    }
+}
+
+impl Player {
+    pub fn other(self) -> Player {
+        match self {
+            Player::South => Player::North,
+            Player::North => Player::South,
+        }
+    }
+
+    fn get_first_pit(self) -> usize {
+        match self {
+            Player::South => 0,
+            Player::North => PIT_COUNT + 1,
+        }
+    }
+
+    fn get_store(self) -> usize {
+        self.get_first_pit() + PIT_COUNT
+    }
+}
+
+impl GameResult {
+    pub fn winner(self) -> Option<Player> {
+        match self {
+            GameResult::Draw => None,
+            GameResult::Win(player) => Some(player),
+        }
+    }
+}
+
+impl Kalah {
+    pub fn new() -> Kalah {
+        Kalah::with_seeds(DEFAULT_SEEDS)
+    }
+
+    pub fn with_seeds(seeds: usize) -> Kalah {
+        if seeds == 0 {
+            panic!("The number of seeds per pit must be positive.");
+        }
+
+        let mut board = [seeds; BOARD_SIZE];
+        board[Player::South.get_store()] = 0;
+        board[Player::North.get_store()] = 0;
+
+        Kalah {
+            status: Status::Running(Player::South),
+            board,
+        }
+    }
+
+    pub fn get_status(&self) -> Status {
+        self.status
+    }
+
+    pub fn get_seeds(&self, player: Player, pit: Pit) -> usize {
+        self.board[player.get_first_pit() + pit.0]
+    }
+
+    pub fn get_store(&self, player: Player) -> usize {
+        self.board[player.get_store()]
+    }
+
+    fn count_pit_seeds(&self, player: Player) -> usize {
+        let first = player.get_first_pit();
+        self.board[first..first + PIT_COUNT].iter().sum()
+    }
+
+    pub fn possible_moves(&self) -> Vec<Pit> {
+        match self.status {
+            Status::Running(player) => Pit::values()
+                .filter(|&pit| self.get_seeds(player, pit) > 0)
+                .collect(),
+            Status::Finished(_) => Vec::new(),
+        }
+    }
+
+    fn sow(&mut self, player: Player, pit: Pit) -> usize {
+        let mut index = player.get_first_pit() + pit.0;
+        let mut seeds = self.board[index];
+        self.board[index] = 0;
+
+        while seeds > 0 {
+            index = (index + 1) % BOARD_SIZE;
+
+            if index != player.other().get_store() {
+                self.board[index] += 1;
+                seeds -= 1;
+            }
+        }
+
+        index
+    }
+
+    fn capture(&mut self, player: Player, index: usize) {
+        let first = player.get_first_pit();
+
+        if index < first || index >= first + PIT_COUNT || self.board[index] != 1 {
+            return;
+        }
+
+        let opposite = 2 * PIT_COUNT - index;
+
+        if self.board[opposite] > 0 {
+            self.board[player.get_store()] += self.board[opposite] + 1;
+            self.board[opposite] = 0;
+            self.board[index] = 0;
+        }
+    }
+
+    fn sweep(&mut self) {
+        for &player in [Player::South, Player::North].iter() {
+            let first = player.get_first_pit();
+            let seeds = self.count_pit_seeds(player);
+
+            for seeds in self.board[first..first + PIT_COUNT].iter_mut() {
+                *seeds = 0;
+            }
+
+            self.board[player.get_store()] += seeds;
+        }
+    }
+
+    fn update_status(&mut self, last_player: Player, last_index: usize) {
+        if self.count_pit_seeds(Player::South) == 0 || self.count_pit_seeds(Player::North) == 0 {
+            self.sweep();
+
+            let south = self.get_store(Player::South);
+            let north = self.get_store(Player::North);
+
+            self.status = Status::Finished(if south > north {
+                GameResult::Win(Player::South)
+            } else if north > south {
+                GameResult::Win(Player::North)
+            } else {
+                GameResult::Draw
+            });
+        } else if last_index == last_player.get_store() {
+            self.status = Status::Running(last_player);
+        } else {
+            self.status = Status::Running(last_player.other());
+        }
+    }
+
+    pub fn make_move(&mut self, player: Player, pit: Pit) -> Result<(), MoveError> {
+        match self.status {
+            Status::Finished(_) => Err(MoveError::InvalidStatus(self.status)),
+            Status::Running(status_player) => {
+                if self.get_seeds(player, pit) == 0 {
+                    return Err(MoveError::EmptyPit(pit));
+                } else if status_player != player {
+                    return Err(MoveError::WrongPlayer(status_player));
+                }
+
+                let last_index = self.sow(player, pit);
+                self.capture(player, last_index);
+                self.update_status(player, last_index);
+
+                Ok(())
+            }
+        }
+    }
+
+    pub fn get_store_score(&self) -> isize {
+        self.get_store(Player::South) as isize - self.get_store(Player::North) as isize
+    }
+}
+
+impl Default for Kalah {
+    fn default() -> Kalah {
+        Kalah::new()
+    }
+}
@@ -0,0 +1,138 @@
+use ::minimax::{GameState, MinimaxResult};
+
+use std::io;
+
+use kalah::*;
+
+use kalah::GameResult::*;
+use kalah::MoveError::*;
+use kalah::Status::*;
+
+enum KalahError {
+    MoveError(MoveError),
+    InvalidInput(String),
+}
+
+impl From<MoveError> for KalahError {
+    fn from(error: MoveError) -> KalahError {
+        KalahError::MoveError(error)
+    }
+}
+
+fn print_instructions() {
+    println!("-----------------------------");
+    println!("            KALAH            ");
+    println!("-----------------------------");
+    println!();
+    println!("Enter the number of one of your pits (1 to 6) and ENTER to sow its seeds.");
+    println!("Pits are numbered from left to right, as seen from your side of the board.");
+    println!("If the last seed lands in your store, you move again.");
+    println!();
+}
+
+fn print_minimax(game: &Kalah) -> MinimaxResult<Kalah> {
+    const MINIMAX_DEPTH: usize = 7;
+
+    print!("Minimax: ");
+
+    let minimax = match game.minimax(MINIMAX_DEPTH) {
+        Ok(minimax) => {
+            println!("{:?}", minimax.outcome);
+            minimax
+        }
+        Err(error) => {
+            println!("{:?}\n", error);
+            return Err(error);
+        }
+    };
+
+    let moves: Vec<String> = minimax
+        .moves
+        .iter()
+        .map(|pit| (pit.get_index() + 1).to_string())
+        .collect();
+
+    println!("{}\n", moves.join(", "));
+    Ok(minimax)
+}
+
+fn parse_input(input: String) -> Result<Pit, KalahError> {
+    match input.parse::<usize>() {
+        Ok(number) if number > 0 => {
+            Pit::new(number - 1).map_err(|_| KalahError::InvalidInput(input))
+        }
+        _ => Err(KalahError::InvalidInput(input)),
+    }
+}
+
+fn handle_turn(game: &mut Kalah, player: Player) -> Result<(), KalahError> {
+    let input = {
+        let mut buffer = String::new();
+
+        io::stdin()
+            .read_line(&mut buffer)
+            .expect("It was not possible to read user input.");
+
+        println!();
+
+        buffer.trim().to_string()
+    };
+
+    let pit = parse_input(input)?;
+
+    game.make_move(player, pit)?;
+    Ok(())
+}
+
+fn handle_error(error: KalahError) {
+    match error {
+        KalahError::MoveError(InvalidStatus(_)) => {
+            panic!("Cannot make any move now because the status of the game doesn't allow it.");
+        }
+        KalahError::MoveError(WrongPlayer(_)) => {
+            panic!("Cannot make that move because it's not the player's turn.");
+        }
+        KalahError::MoveError(EmptyPit(_)) => {
+            println!("Cannot make that move because that pit is empty.");
+        }
+        KalahError::InvalidInput(input) => {
+            println!("Invalid input: {}", input);
+            println!("Please, enter a pit number from 1 to 6.");
+        }
+    }
+
+    println!();
+}
+
+fn main() {
+    print_instructions();
+
+    let mut game = Kalah::new();
+
+    println!("{}", game);
+
+    loop {
+        match game.get_status() {
+            Running(player) => {
+                println!("{} to move.", player);
+                print_minimax(&game).ok();
+
+                let result = handle_turn(&mut game, player);
+
+                if let Err(error) = result {
+                    handle_error(error);
+                }
+
+                println!("{}", game);
+            }
+            Finished(result) => {
+                match result {
+                    Draw => println!("Draw."),
+                    Win(player) => println!("{} wins.", player),
+                }
+
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use crate::GameResult::*;
+use crate::MoveError::*;
+use crate::Status::*;
+use crate::*;
+
+use ::minimax::transposition::TranspositionTable;
+use ::minimax::Outcome::*;
+use ::minimax::{GameResult as MinimaxGameResult, GameState};
+
+use std::collections::HashMap;
+
+fn pit(index: usize) -> Pit {
+    Pit::new(index).unwrap()
+}
+
+fn kalah(south: [usize; PIT_COUNT + 1], north: [usize; PIT_COUNT + 1], player: Player) -> Kalah {
+    let mut board = [0; BOARD_SIZE];
+    board[..=PIT_COUNT].copy_from_slice(&south);
+    board[PIT_COUNT + 1..].copy_from_slice(&north);
+
+    Kalah {
+        status: Running(player),
+        board,
+    }
+}
+
+fn solve(game: &Kalah, results: &mut HashMap<Kalah, GameResult>) -> GameResult {
+    let player = match game.get_status() {
+        Running(player) => player,
+        Finished(result) => return result,
+    };
+
+    if let Some(&result) = results.get(game) {
+        return result;
+    }
+
+    let children: Vec<GameResult> = game
+        .possible_moves()
+        .into_iter()
+        .map(|pit| {
+            let mut child = game.clone();
+            child.make_move(player, pit).unwrap();
+            solve(&child, results)
+        })
+        .collect();
+
+    let result = if children.contains(&Win(player)) {
+        Win(player)
+    } else if children.contains(&Draw) {
+        Draw
+    } else {
+        Win(player.other())
+    };
+
+    results.insert(game.clone(), result);
+    result
+}
+
+#[test]
+fn test_kalah() {
+    let mut game = Kalah::new();
+
+    assert_eq!(game.possible_moves(), Pit::values().collect::<Vec<_>>());
+
+    assert_eq!(game.make_move(Player::South, pit(2)), Ok(()));
+    assert_eq!(game.get_store(Player::South), 1);
+    assert_eq!(game.get_status(), Running(Player::South));
+
+    assert_eq!(
+        game.make_move(Player::North, pit(0)),
+        Err(WrongPlayer(Player::South))
+    );
+
+    assert_eq!(game.make_move(Player::South, pit(2)), Err(EmptyPit(pit(2))));
+
+    assert_eq!(game.make_move(Player::South, pit(5)), Ok(()));
+    assert_eq!(game.get_store(Player::South), 2);
+    assert_eq!(game.get_seeds(Player::North, pit(3)), 5);
+    assert_eq!(game.get_seeds(Player::North, pit(4)), 4);
+    assert_eq!(game.get_status(), Running(Player::North));
+    assert_eq!(game.get_store_score(), 2);
+}
+
+#[test]
+fn test_capture_and_sweep() {
+    let mut game = kalah([1, 0, 0, 0, 0, 0, 0], [1, 0, 0, 0, 3, 0, 0], Player::South);
+
+    assert_eq!(game.make_move(Player::South, pit(0)), Ok(()));
+    assert_eq!(game.get_seeds(Player::South, pit(1)), 0);
+    assert_eq!(game.get_seeds(Player::North, pit(4)), 0);
+    assert_eq!(game.get_store(Player::South), 4);
+    assert_eq!(game.get_store(Player::North), 1);
+    assert_eq!(game.get_status(), Finished(Win(Player::South)));
+
+    assert_eq!(
+        game.make_move(Player::North, pit(0)),
+        Err(InvalidStatus(Finished(Win(Player::South))))
+    );
+}
+
+#[test]
+fn test_consecutive_turns() {
+    let mut game = kalah([0, 0, 0, 0, 2, 1, 0], [1, 0, 0, 0, 0, 0, 1], Player::South);
+
+    let minimax = game.minimax(4).unwrap();
+    assert_eq!(
+        minimax.outcome,
+        Definite(MinimaxGameResult::Win(Player::South.into()), 2)
+    );
+    assert_eq!(minimax.moves, vec![pit(5)]);
+
+    assert_eq!(game.make_move(Player::South, pit(5)), Ok(()));
+    assert_eq!(game.get_status(), Running(Player::South));
+
+    let minimax = game.minimax(4).unwrap();
+    assert_eq!(
+        minimax.outcome,
+        Definite(MinimaxGameResult::Win(Player::South.into()), 1)
+    );
+    assert_eq!(minimax.moves, vec![pit(4)]);
+
+    assert_eq!(game.make_move(Player::South, pit(4)), Ok(()));
+    assert_eq!(game.get_status(), Running(Player::South));
+    assert_eq!(game.make_move(Player::South, pit(5)), Ok(()));
+    assert_eq!(game.get_store(Player::South), 3);
+    assert_eq!(game.get_store(Player::North), 2);
+    assert_eq!(game.get_status(), Finished(Win(Player::South)));
+}
+
+#[test]
+fn test_solved_endgames() {
+    let games = [
+        kalah([1, 1, 1, 0, 0, 0, 0], [1, 1, 1, 0, 0, 0, 0], Player::South),
+        kalah([0, 2, 0, 1, 0, 1, 3], [1, 0, 2, 0, 1, 0, 4], Player::North),
+        kalah([2, 0, 0, 0, 1, 1, 0], [0, 0, 3, 0, 0, 1, 0], Player::South),
+        kalah([0, 0, 3, 0, 2, 0, 5], [2, 1, 0, 0, 1, 1, 6], Player::North),
+    ];
+
+    let mut results = HashMap::new();
+    let mut table = TranspositionTable::new();
+
+    for game in games.iter() {
+        let result = solve(game, &mut results);
+
+        match table.minimax(game, 20).unwrap().outcome {
+            Definite(minimax_result, _) => assert_eq!(GameResult::from(minimax_result), result),
+            outcome => panic!("Unexpected outcome {:?}.", outcome),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::*;
+
+use std::fmt;
+
+impl fmt::Display for Player {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Player::First => write!(f, "First player"),
+            Player::Second => write!(f, "Second player"),
+        }
+    }
+}
+
+impl fmt::Display for Nim {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = self.get_heaps().len().to_string().len();
+
+        for (i, &heap) in self.get_heaps().iter().enumerate() {
+            writeln!(f, "{:>2$}: {}", i + 1, "|".repeat(heap), digits)?;
+        }
+
+        Ok(())
+    }
+}
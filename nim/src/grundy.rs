@@ -0,0 +1,44 @@
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GrundyCalculator {
+    max_take: Option<usize>,
+    values: Vec<usize>,
+}
+
+pub fn mex(values: impl Iterator<Item = usize>) -> usize {
+    let mut values: Vec<usize> = values.collect();
+    values.sort_unstable();
+    values.dedup();
+
+    values
+        .iter()
+        .enumerate()
+        .find(|&(i, &value)| i != value)
+        .map_or(values.len(), |(i, _value)| i)
+}
+
+impl GrundyCalculator {
+    pub fn new(max_take: Option<usize>) -> GrundyCalculator {
+        GrundyCalculator {
+            max_take,
+            values: vec![0],
+        }
+    }
+
+    pub fn get_heap_value(&mut self, heap: usize) -> usize {
+        while self.values.len() <= heap {
+            let n = self.values.len();
+            let max_take = self.max_take.map_or(n, |max_take| max_take.min(n));
+            let value = mex((1..=max_take).map(|take| self.values[n - take]));
+
+            self.values.push(value);
+        }
+
+        self.values[heap]
+    }
+
+    pub fn get_position_value(&mut self, heaps: &[usize]) -> usize {
+        heaps
+            .iter()
+            .fold(0, |nim_sum, &heap| nim_sum ^ self.get_heap_value(heap))
+    }
+}
@@ -0,0 +1,183 @@
+mod display;
+mod grundy;
+mod minimax;
+
+#[cfg(test)]
+mod test;
+
+pub use crate::grundy::{mex, GrundyCalculator};
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Player {
+    First,
+    Second,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Variant {
+    Normal,
+    Misere,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Status {
+    Running(Player),
+    Finished(Player),
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Move {
+    pub heap: usize,
+    pub count: usize,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Nim {
+    status: Status,
+    variant: Variant,
+    max_take: Option<usize>,
+    heaps: Vec<usize>,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum MoveError {
+    InvalidStatus(Status),
+    WrongPlayer(Player),
+    InvalidHeap(usize),
+    InvalidCount(usize),
+}
+
+impl Player {
+    pub fn other(self) -> Player {
+        match self {
+            Player::First => Player::Second,
+            Player::Second => Player::First,
+        }
+    }
+}
+
+impl Move {
+    pub fn new(heap: usize, count: usize) -> Move {
+        Move { heap, count }
+    }
+}
+
+impl Nim {
+    pub fn new(heaps: &[usize], variant: Variant) -> Nim {
+        Nim::with_max_take(heaps, variant, None)
+    }
+
+    pub fn with_max_take(heaps: &[usize], variant: Variant, max_take: Option<usize>) -> Nim {
+        if max_take == Some(0) {
+            panic!("The maximum number of objects to take must be positive.");
+        }
+
+        let mut nim = Nim {
+            status: Status::Running(Player::First),
+            variant,
+            max_take,
+            heaps: heaps.to_vec(),
+        };
+
+        nim.update_status(Player::Second);
+        nim
+    }
+
+    pub fn get_status(&self) -> Status {
+        self.status
+    }
+
+    pub fn get_variant(&self) -> Variant {
+        self.variant
+    }
+
+    pub fn get_max_take(&self) -> Option<usize> {
+        self.max_take
+    }
+
+    pub fn get_heaps(&self) -> &[usize] {
+        &self.heaps
+    }
+
+    fn get_take_limit(&self, heap: usize) -> usize {
+        match self.max_take {
+            Some(max_take) => max_take.min(heap),
+            None => heap,
+        }
+    }
+
+    pub fn possible_moves(&self) -> Vec<Move> {
+        match self.status {
+            Status::Running(_) => self
+                .heaps
+                .iter()
+                .enumerate()
+                .flat_map(|(i, &heap)| {
+                    (1..=self.get_take_limit(heap)).map(move |n| Move::new(i, n))
+                })
+                .collect(),
+            Status::Finished(_) => Vec::new(),
+        }
+    }
+
+    fn update_status(&mut self, last_player: Player) {
+        self.status = if self.heaps.iter().all(|&heap| heap == 0) {
+            match self.variant {
+                Variant::Normal => Status::Finished(last_player),
+                Variant::Misere => Status::Finished(last_player.other()),
+            }
+        } else {
+            Status::Running(last_player.other())
+        };
+    }
+
+    pub fn make_move(&mut self, player: Player, mov: Move) -> Result<(), MoveError> {
+        match self.status {
+            Status::Finished(_) => Err(MoveError::InvalidStatus(self.status)),
+            Status::Running(status_player) => {
+                let heap = *self
+                    .heaps
+                    .get(mov.heap)
+                    .ok_or(MoveError::InvalidHeap(mov.heap))?;
+
+                if mov.count == 0 || mov.count > self.get_take_limit(heap) {
+                    return Err(MoveError::InvalidCount(mov.count));
+                } else if status_player != player {
+                    return Err(MoveError::WrongPlayer(status_player));
+                }
+
+                self.heaps[mov.heap] -= mov.count;
+                self.update_status(player);
+
+                Ok(())
+            }
+        }
+    }
+
+    pub fn get_theoretical_winner(&self) -> Option<Player> {
+        let player = match self.status {
+            Status::Running(player) => player,
+            Status::Finished(winner) => return Some(winner),
+        };
+
+        let nim_sum = GrundyCalculator::new(self.max_take).get_position_value(&self.heaps);
+
+        let wins = match (self.variant, self.max_take) {
+            (Variant::Normal, _) => nim_sum != 0,
+            (Variant::Misere, None) => {
+                if self.heaps.iter().all(|&heap| heap <= 1) {
+                    nim_sum == 0
+                } else {
+                    nim_sum != 0
+                }
+            }
+            (Variant::Misere, Some(_)) => return None,
+        };
+
+        if wins {
+            Some(player)
+        } else {
+            Some(player.other())
+        }
+    }
+}
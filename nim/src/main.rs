@@ -0,0 +1,154 @@
+use ::minimax::{GameState, MinimaxResult};
+
+use std::env;
+use std::io;
+
+use nim::*;
+
+use nim::MoveError::*;
+use nim::Status::*;
+
+enum NimError {
+    MoveError(MoveError),
+    InvalidInput(String),
+}
+
+impl From<MoveError> for NimError {
+    fn from(error: MoveError) -> NimError {
+        NimError::MoveError(error)
+    }
+}
+
+fn print_instructions(nim: &Nim) {
+    println!("-----------------------------");
+    println!("             NIM             ");
+    println!("-----------------------------");
+    println!();
+
+    match nim.get_variant() {
+        Variant::Normal => println!("The player who takes the last object wins."),
+        Variant::Misere => println!("The player who takes the last object loses."),
+    }
+
+    println!("Enter the move in the following format: <heap> <count>");
+    println!("Where <heap> is the number of the heap and <count> is how many objects to take.");
+    println!();
+}
+
+fn print_minimax(nim: &Nim) -> MinimaxResult<Nim> {
+    const MINIMAX_DEPTH: usize = 8;
+
+    print!("Minimax: ");
+
+    let minimax = match nim.minimax(MINIMAX_DEPTH) {
+        Ok(minimax) => {
+            println!("{:?}", minimax.outcome);
+            minimax
+        }
+        Err(error) => {
+            println!("{:?}\n", error);
+            return Err(error);
+        }
+    };
+
+    let moves: Vec<String> = minimax
+        .moves
+        .iter()
+        .map(|mov| format!("{} {}", mov.heap + 1, mov.count))
+        .collect();
+
+    println!("{}\n", moves.join(", "));
+    Ok(minimax)
+}
+
+fn parse_input(input: String) -> Result<Move, NimError> {
+    let values: Vec<usize> = input
+        .split_whitespace()
+        .map(|s| s.parse::<usize>())
+        .collect::<Result<_, _>>()
+        .map_err(|_| NimError::InvalidInput(input.clone()))?;
+
+    match values.as_slice() {
+        [heap, count] if *heap > 0 => Ok(Move::new(heap - 1, *count)),
+        _ => Err(NimError::InvalidInput(input)),
+    }
+}
+
+fn handle_turn(nim: &mut Nim, player: Player) -> Result<(), NimError> {
+    let input = {
+        let mut buffer = String::new();
+
+        io::stdin()
+            .read_line(&mut buffer)
+            .expect("It was not possible to read user input.");
+
+        println!();
+
+        buffer.trim().to_string()
+    };
+
+    let mov = parse_input(input)?;
+
+    nim.make_move(player, mov)?;
+    Ok(())
+}
+
+fn handle_error(error: NimError) {
+    match error {
+        NimError::MoveError(InvalidStatus(_)) => {
+            panic!("Cannot make any move now because the status of the game doesn't allow it.");
+        }
+        NimError::MoveError(WrongPlayer(_)) => {
+            panic!("Cannot make that move because it's not the player's turn.");
+        }
+        NimError::MoveError(InvalidHeap(_)) => {
+            println!("Cannot make that move because that heap doesn't exist.");
+        }
+        NimError::MoveError(InvalidCount(_)) => {
+            println!("Cannot make that move because that many objects can't be taken.");
+        }
+        NimError::InvalidInput(input) => {
+            println!("Invalid input: {}", input);
+        }
+    }
+
+    println!();
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let variant = if args.iter().any(|arg| arg == "--misere") {
+        Variant::Misere
+    } else {
+        Variant::Normal
+    };
+
+    let heaps: Vec<usize> = args.iter().filter_map(|arg| arg.parse().ok()).collect();
+
+    let mut nim = if heaps.is_empty() {
+        Nim::new(&[3, 4, 5], variant)
+    } else {
+        Nim::new(&heaps, variant)
+    };
+
+    print_instructions(&nim);
+    println!("{}", nim);
+
+    while let Running(player) = nim.get_status() {
+        println!("{} to move.", player);
+        print_minimax(&nim).ok();
+
+        let result = handle_turn(&mut nim, player);
+
+        if let Err(error) = result {
+            handle_error(error);
+        }
+
+        println!("{}", nim);
+    }
+
+    if let Finished(winner) = nim.get_status() {
+        println!("{} wins.", winner);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::MoveError::*;
+use crate::Status::*;
+use crate::*;
+
+use ::minimax::transposition::TranspositionTable;
+use ::minimax::GameResult as MinimaxGameResult;
+use ::minimax::Outcome::*;
+
+fn get_positions(heap_count: usize, max_heap: usize) -> Vec<Vec<usize>> {
+    (0..heap_count).fold(vec![Vec::new()], |positions, _| {
+        positions
+            .into_iter()
+            .flat_map(|heaps| {
+                (0..=max_heap).map(move |heap| {
+                    let mut heaps = heaps.clone();
+                    heaps.push(heap);
+                    heaps
+                })
+            })
+            .collect()
+    })
+}
+
+fn assert_theory(variant: Variant, max_take: Option<usize>) {
+    let mut table = TranspositionTable::new();
+
+    for heaps in get_positions(3, 5) {
+        let nim = Nim::with_max_take(&heaps, variant, max_take);
+
+        if let Finished(_) = nim.get_status() {
+            continue;
+        }
+
+        let depth = heaps.iter().sum();
+
+        let winner = match table.minimax(&nim, depth).unwrap().outcome {
+            Definite(MinimaxGameResult::Win(winner), _) => Player::from(winner),
+            outcome => panic!("Unexpected outcome {:?} for {:?}.", outcome, heaps),
+        };
+
+        assert_eq!(Some(winner), nim.get_theoretical_winner(), "{:?}", heaps);
+    }
+}
+
+#[test]
+fn test_nim() {
+    let mut nim = Nim::new(&[3, 1], Variant::Normal);
+
+    assert_eq!(nim.possible_moves().len(), 4);
+
+    assert_eq!(
+        nim.make_move(Player::First, Move::new(2, 1)),
+        Err(InvalidHeap(2))
+    );
+
+    assert_eq!(
+        nim.make_move(Player::First, Move::new(1, 2)),
+        Err(InvalidCount(2))
+    );
+
+    assert_eq!(
+        nim.make_move(Player::Second, Move::new(0, 2)),
+        Err(WrongPlayer(Player::First))
+    );
+
+    assert_eq!(nim.make_move(Player::First, Move::new(0, 2)), Ok(()));
+    assert_eq!(nim.make_move(Player::Second, Move::new(1, 1)), Ok(()));
+    assert_eq!(nim.get_status(), Running(Player::First));
+    assert_eq!(nim.make_move(Player::First, Move::new(0, 1)), Ok(()));
+    assert_eq!(nim.get_status(), Finished(Player::First));
+
+    assert_eq!(
+        nim.make_move(Player::Second, Move::new(0, 1)),
+        Err(InvalidStatus(Finished(Player::First)))
+    );
+
+    let mut nim = Nim::new(&[1], Variant::Misere);
+    assert_eq!(nim.make_move(Player::First, Move::new(0, 1)), Ok(()));
+    assert_eq!(nim.get_status(), Finished(Player::Second));
+
+    assert_eq!(
+        Nim::new(&[], Variant::Normal).get_status(),
+        Finished(Player::Second)
+    );
+    assert_eq!(
+        Nim::new(&[0, 0], Variant::Misere).get_status(),
+        Finished(Player::First)
+    );
+}
+
+#[test]
+fn test_grundy() {
+    assert_eq!(mex([0, 1, 3].iter().cloned()), 2);
+    assert_eq!(mex([2, 1, 0, 1].iter().cloned()), 3);
+    assert_eq!(mex(std::iter::empty()), 0);
+
+    let mut calculator = GrundyCalculator::new(None);
+
+    for heap in 0..10 {
+        assert_eq!(calculator.get_heap_value(heap), heap);
+    }
+
+    assert_eq!(calculator.get_position_value(&[1, 2, 3]), 0);
+    assert_eq!(calculator.get_position_value(&[3, 4, 5]), 2);
+
+    let mut calculator = GrundyCalculator::new(Some(3));
+
+    for heap in 0..10 {
+        assert_eq!(calculator.get_heap_value(heap), heap % 4);
+    }
+}
+
+#[test]
+fn test_normal_play_theory() {
+    assert_theory(Variant::Normal, None);
+    assert_theory(Variant::Normal, Some(2));
+}
+
+#[test]
+fn test_misere_play_theory() {
+    assert_theory(Variant::Misere, None);
+}
@@ -0,0 +1,57 @@
+use crate::*;
+
+use std::fmt;
+
+fn get_char(player: Option<Player>) -> char {
+    match player {
+        None => '.',
+        Some(Player::Black) => 'B',
+        Some(Player::White) => 'W',
+    }
+}
+
+impl fmt::Display for Player {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Player::Black => write!(f, "Black"),
+            Player::White => write!(f, "White"),
+        }
+    }
+}
+
+impl fmt::Display for Place {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (x, y) = self.get_xy();
+        write!(f, "{}{}", (b'A' + x as u8) as char, y + 1)
+    }
+}
+
+impl fmt::Display for Othello {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "  ")?;
+
+        for x in 0..GRID_WIDTH {
+            write!(f, " {}", (b'A' + x as u8) as char)?;
+        }
+
+        writeln!(f)?;
+
+        for y in 0..GRID_HEIGHT {
+            write!(f, "{:>2}", y + 1)?;
+
+            for x in 0..GRID_WIDTH {
+                let place = Place::from_xy(x, y).expect("Invalid place.");
+                write!(f, " {}", get_char(self[place]))?;
+            }
+
+            writeln!(f)?;
+        }
+
+        writeln!(
+            f,
+            "Black: {}, White: {}",
+            self.count_discs(Player::Black),
+            self.count_discs(Player::White)
+        )
+    }
+}
@@ -0,0 +1,296 @@
+mod display;
+mod minimax;
+
+#[cfg(test)]
+mod test;
+
+use std::ops::Index;
+
+const GRID_WIDTH: usize = 8;
+const GRID_HEIGHT: usize = 8;
+const GRID_SIZE: usize = GRID_WIDTH * GRID_HEIGHT;
+const MOBILITY_WEIGHT: isize = 5;
+
+const DIRECTIONS: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+const SQUARE_WEIGHTS: [isize; GRID_SIZE] = [
+    100, -20, 10, 5, 5, 10, -20, 100, //
+    -20, -50, -2, -2, -2, -2, -50, -20, //
+    10, -2, 1, 1, 1, 1, -2, 10, //
+    5, -2, 1, 0, 0, 1, -2, 5, //
+    5, -2, 1, 0, 0, 1, -2, 5, //
+    10, -2, 1, 1, 1, 1, -2, 10, //
+    -20, -50, -2, -2, -2, -2, -50, -20, //
+    100, -20, 10, 5, 5, 10, -20, 100, //
+];
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Place(usize);
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct InvalidPlace;
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Player {
+    Black,
+    White,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Move {
+    Place(Place),
+    Pass,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum GameResult {
+    Draw,
+    Win(Player),
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Status {
+    Running(Player),
+    Finished(GameResult),
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Othello {
+    status: Status,
+    grid: [Option<Player>; GRID_SIZE],
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum MoveError {
+    InvalidStatus(Status),
+    WrongPlayer(Player),
+    PlaceAlreadyUsed(Place, Player),
+    NoDiscsFlipped(Place),
+    PassNotAllowed,
+}
+
+impl Place {
+    pub fn from_xy(x: usize, y: usize) -> Result<Place, InvalidPlace> {
+        if x >= GRID_WIDTH || y >= GRID_HEIGHT {
+            Err(InvalidPlace)
+        } else {
+            Ok(Place(y * GRID_WIDTH + x))
+        }
+    }
+
+    pub fn get_xy(self) -> (usize, usize) {
+        (self.0 % GRID_WIDTH, self.0 / GRID_WIDTH)
+    }
+
+    pub fn values() -> impl Iterator<Item = Place> {
+        (0..GRID_SIZE).map(Place)
+    }
+
+    fn offset(self, dx: isize, dy: isize) -> Option<Place> {
+        let (x, y) = self.get_xy();
+        let x = x as isize + dx;
+        let y = y as isize + dy;
+
+        if x < 0 || y < 0 {
+            None
+        } else {
+            Place::from_xy(x as usize, y as usize).ok()
+        }
+    }
+}
+
+impl Player {
+    pub fn other(self) -> Player {
+        match self {
+            Player::Black => Player::White,
+            Player::White => Player::Black,
+        }
+    }
+}
+
+impl GameResult {
+    pub fn winner(self) -> Option<Player> {
+        match self {
+            GameResult::Draw => None,
+            GameResult::Win(player) => Some(player),
+        }
+    }
+}
+
+impl Othello {
+    pub fn new() -> Othello {
+        let mut grid = [None; GRID_SIZE];
+
+        grid[Place::from_xy(3, 3).unwrap().0] = Some(Player::White);
+        grid[Place::from_xy(4, 4).unwrap().0] = Some(Player::White);
+        grid[Place::from_xy(3, 4).unwrap().0] = Some(Player::Black);
+        grid[Place::from_xy(4, 3).unwrap().0] = Some(Player::Black);
+
+        Othello {
+            status: Status::Running(Player::Black),
+            grid,
+        }
+    }
+
+    pub fn get_status(&self) -> Status {
+        self.status
+    }
+
+    pub fn count_discs(&self, player: Player) -> usize {
+        self.grid.iter().filter(|&&p| p == Some(player)).count()
+    }
+
+    fn get_flips(&self, player: Player, place: Place) -> Vec<Place> {
+        if self[place].is_some() {
+            return Vec::new();
+        }
+
+        let mut flips = Vec::new();
+
+        for &(dx, dy) in DIRECTIONS.iter() {
+            let mut line = Vec::new();
+            let mut cursor = place.offset(dx, dy);
+
+            while let Some(p) = cursor {
+                match self[p] {
+                    Some(disc) if disc == player.other() => line.push(p),
+                    Some(_) => {
+                        flips.append(&mut line);
+                        break;
+                    }
+                    None => break,
+                }
+
+                cursor = p.offset(dx, dy);
+            }
+        }
+
+        flips
+    }
+
+    pub fn get_placements(&self, player: Player) -> Vec<Place> {
+        Place::values()
+            .filter(|&p| !self.get_flips(player, p).is_empty())
+            .collect()
+    }
+
+    pub fn possible_moves(&self) -> Vec<Move> {
+        match self.status {
+            Status::Running(player) => {
+                let placements = self.get_placements(player);
+
+                if placements.is_empty() {
+                    vec![Move::Pass]
+                } else {
+                    placements.into_iter().map(Move::Place).collect()
+                }
+            }
+            Status::Finished(_) => Vec::new(),
+        }
+    }
+
+    fn update_status(&mut self, last_player: Player) {
+        let next_player = last_player.other();
+
+        self.status = if !self.get_placements(next_player).is_empty()
+            || !self.get_placements(last_player).is_empty()
+        {
+            Status::Running(next_player)
+        } else {
+            let black = self.count_discs(Player::Black);
+            let white = self.count_discs(Player::White);
+
+            Status::Finished(if black > white {
+                GameResult::Win(Player::Black)
+            } else if white > black {
+                GameResult::Win(Player::White)
+            } else {
+                GameResult::Draw
+            })
+        };
+    }
+
+    pub fn make_move(&mut self, player: Player, mov: Move) -> Result<(), MoveError> {
+        match self.status {
+            Status::Finished(_) => Err(MoveError::InvalidStatus(self.status)),
+            Status::Running(status_player) => {
+                if status_player != player {
+                    return Err(MoveError::WrongPlayer(status_player));
+                }
+
+                match mov {
+                    Move::Place(place) => self.make_place_move(player, place)?,
+                    Move::Pass => {
+                        if !self.get_placements(player).is_empty() {
+                            return Err(MoveError::PassNotAllowed);
+                        }
+                    }
+                }
+
+                self.update_status(player);
+                Ok(())
+            }
+        }
+    }
+
+    fn make_place_move(&mut self, player: Player, place: Place) -> Result<(), MoveError> {
+        if let Some(place_player) = self[place] {
+            return Err(MoveError::PlaceAlreadyUsed(place, place_player));
+        }
+
+        let flips = self.get_flips(player, place);
+
+        if flips.is_empty() {
+            return Err(MoveError::NoDiscsFlipped(place));
+        }
+
+        self.grid[place.0] = Some(player);
+
+        for p in flips {
+            self.grid[p.0] = Some(player);
+        }
+
+        Ok(())
+    }
+
+    pub fn get_positional_score(&self) -> isize {
+        let squares: isize = self
+            .grid
+            .iter()
+            .zip(SQUARE_WEIGHTS.iter())
+            .map(|(disc, &weight)| match disc {
+                Some(Player::Black) => weight,
+                Some(Player::White) => -weight,
+                None => 0,
+            })
+            .sum();
+
+        let mobility = self.get_placements(Player::Black).len() as isize
+            - self.get_placements(Player::White).len() as isize;
+
+        squares + MOBILITY_WEIGHT * mobility
+    }
+}
+
+impl Default for Othello {
+    fn default() -> Othello {
+        Othello::new()
+    }
+}
+
+impl Index<Place> for Othello {
+    type Output = Option<Player>;
+
+    fn index(&self, place: Place) -> &Option<Player> {
+        &self.grid[place.0]
+    }
+}
@@ -0,0 +1,159 @@
+use ::minimax::{GameState, MinimaxResult};
+
+use std::io;
+
+use othello::*;
+
+use othello::GameResult::*;
+use othello::MoveError::*;
+use othello::Status::*;
+
+enum OthelloError {
+    MoveError(MoveError),
+    InvalidInput(String),
+}
+
+impl From<MoveError> for OthelloError {
+    fn from(error: MoveError) -> OthelloError {
+        OthelloError::MoveError(error)
+    }
+}
+
+fn print_instructions() {
+    println!("-----------------------------");
+    println!("           OTHELLO           ");
+    println!("-----------------------------");
+    println!();
+    println!("Enter the column letter and the row number (e.g. D3) and ENTER to place a disc.");
+    println!("Enter PASS when there is no place to put a disc.");
+    println!();
+}
+
+fn print_minimax(game: &Othello) -> MinimaxResult<Othello> {
+    const MINIMAX_DEPTH: usize = 4;
+
+    print!("Minimax: ");
+
+    let minimax = match game.minimax(MINIMAX_DEPTH) {
+        Ok(minimax) => {
+            println!("{:?}", minimax.outcome);
+            minimax
+        }
+        Err(error) => {
+            println!("{:?}\n", error);
+            return Err(error);
+        }
+    };
+
+    let moves: Vec<String> = minimax
+        .moves
+        .iter()
+        .map(|mov| match mov {
+            Move::Place(place) => place.to_string(),
+            Move::Pass => "PASS".to_string(),
+        })
+        .collect();
+
+    println!("{}\n", moves.join(", "));
+    Ok(minimax)
+}
+
+fn parse_input(input: String) -> Result<Move, OthelloError> {
+    if input == "PASS" {
+        return Ok(Move::Pass);
+    }
+
+    let mut chars = input.chars();
+
+    let x = match chars.next() {
+        Some(c @ 'A'..='H') => c as usize - 'A' as usize,
+        _ => return Err(OthelloError::InvalidInput(input)),
+    };
+
+    let y = match chars.as_str().parse::<usize>() {
+        Ok(y) if y > 0 => y - 1,
+        _ => return Err(OthelloError::InvalidInput(input)),
+    };
+
+    Place::from_xy(x, y)
+        .map(Move::Place)
+        .map_err(|_| OthelloError::InvalidInput(input))
+}
+
+fn handle_turn(game: &mut Othello, player: Player) -> Result<(), OthelloError> {
+    let input = {
+        let mut buffer = String::new();
+
+        io::stdin()
+            .read_line(&mut buffer)
+            .expect("It was not possible to read user input.");
+
+        println!();
+
+        buffer.trim().to_uppercase()
+    };
+
+    let mov = parse_input(input)?;
+
+    game.make_move(player, mov)?;
+    Ok(())
+}
+
+fn handle_error(error: OthelloError) {
+    match error {
+        OthelloError::MoveError(InvalidStatus(_)) => {
+            panic!("Cannot make any move now because the status of the game doesn't allow it.");
+        }
+        OthelloError::MoveError(WrongPlayer(_)) => {
+            panic!("Cannot make that move because it's not the player's turn.");
+        }
+        OthelloError::MoveError(PlaceAlreadyUsed(_, _)) => {
+            println!("Cannot make that move because that place is already used.");
+        }
+        OthelloError::MoveError(NoDiscsFlipped(_)) => {
+            println!("Cannot make that move because it doesn't flip any disc.");
+        }
+        OthelloError::MoveError(PassNotAllowed) => {
+            println!("Cannot pass because there are places to put a disc.");
+        }
+        OthelloError::InvalidInput(input) => {
+            println!("Invalid input: {}", input);
+            println!("Please, enter a place from A1 to H8, or PASS.");
+        }
+    }
+
+    println!();
+}
+
+fn main() {
+    print_instructions();
+
+    let mut game = Othello::new();
+
+    println!("{}", game);
+
+    loop {
+        match game.get_status() {
+            Running(player) => {
+                println!("{} to move.", player);
+                print_minimax(&game).ok();
+
+                let result = handle_turn(&mut game, player);
+
+                if let Err(error) = result {
+                    handle_error(error);
+                }
+
+                println!("{}", game);
+            }
+            Finished(result) => {
+                match result {
+                    Draw => println!("Draw."),
+                    Win(player) => println!("{} wins.", player),
+                }
+
+                break;
+            }
+        }
+    }
+}
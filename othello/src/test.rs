@@ -0,0 +1,147 @@
+use crate::GameResult::*;
+use crate::MoveError::*;
+use crate::Status::*;
+use crate::*;
+
+use ::minimax::Outcome::*;
+use ::minimax::{GameResult as MinimaxGameResult, GameState};
+
+fn place(x: usize, y: usize) -> Place {
+    Place::from_xy(x, y).unwrap()
+}
+
+#[test]
+fn test_othello() {
+    let mut game = Othello::new();
+
+    assert_eq!(
+        game.possible_moves(),
+        vec![
+            Move::Place(place(3, 2)),
+            Move::Place(place(2, 3)),
+            Move::Place(place(5, 4)),
+            Move::Place(place(4, 5)),
+        ]
+    );
+
+    assert_eq!(
+        game.make_move(Player::White, Move::Place(place(3, 2))),
+        Err(WrongPlayer(Player::Black))
+    );
+
+    assert_eq!(
+        game.make_move(Player::Black, Move::Place(place(3, 3))),
+        Err(PlaceAlreadyUsed(place(3, 3), Player::White))
+    );
+
+    assert_eq!(
+        game.make_move(Player::Black, Move::Place(place(0, 0))),
+        Err(NoDiscsFlipped(place(0, 0)))
+    );
+
+    assert_eq!(
+        game.make_move(Player::Black, Move::Pass),
+        Err(PassNotAllowed)
+    );
+
+    assert_eq!(
+        game.make_move(Player::Black, Move::Place(place(3, 2))),
+        Ok(())
+    );
+    assert_eq!(game[place(3, 3)], Some(Player::Black));
+    assert_eq!(game.count_discs(Player::Black), 4);
+    assert_eq!(game.count_discs(Player::White), 1);
+    assert_eq!(game.get_status(), Running(Player::White));
+
+    assert_eq!(
+        game.make_move(Player::White, Move::Place(place(2, 2))),
+        Ok(())
+    );
+    assert_eq!(game[place(3, 3)], Some(Player::White));
+    assert_eq!(game.get_status(), Running(Player::Black));
+}
+
+#[test]
+fn test_pass() {
+    let mut grid = [None; GRID_SIZE];
+    grid[place(0, 0).0] = Some(Player::Black);
+    grid[place(1, 0).0] = Some(Player::White);
+
+    let mut game = Othello {
+        status: Running(Player::White),
+        grid,
+    };
+
+    assert_eq!(game.possible_moves(), vec![Move::Pass]);
+
+    assert_eq!(
+        game.make_move(Player::White, Move::Place(place(2, 0))),
+        Err(NoDiscsFlipped(place(2, 0)))
+    );
+
+    let minimax = game.minimax(3).unwrap();
+    assert_eq!(
+        minimax.outcome,
+        Definite(MinimaxGameResult::Win(Player::Black.into()), 1)
+    );
+    assert_eq!(minimax.moves, vec![Move::Pass]);
+
+    assert_eq!(game.make_move(Player::White, Move::Pass), Ok(()));
+    assert_eq!(game.get_status(), Running(Player::Black));
+    assert_eq!(game.possible_moves(), vec![Move::Place(place(2, 0))]);
+
+    assert_eq!(
+        game.make_move(Player::Black, Move::Place(place(2, 0))),
+        Ok(())
+    );
+    assert_eq!(game.get_status(), Finished(Win(Player::Black)));
+
+    assert_eq!(
+        game.make_move(Player::White, Move::Pass),
+        Err(InvalidStatus(Finished(Win(Player::Black))))
+    );
+}
+
+#[test]
+fn test_consecutive_turns() {
+    let mut grid = [None; GRID_SIZE];
+    grid[place(0, 0).0] = Some(Player::Black);
+    grid[place(1, 0).0] = Some(Player::White);
+    grid[place(3, 0).0] = Some(Player::White);
+
+    let mut game = Othello {
+        status: Running(Player::Black),
+        grid,
+    };
+
+    assert_eq!(
+        game.make_move(Player::Black, Move::Place(place(2, 0))),
+        Ok(())
+    );
+    assert_eq!(game.get_status(), Running(Player::White));
+    assert_eq!(game.possible_moves(), vec![Move::Pass]);
+    assert_eq!(game.make_move(Player::White, Move::Pass), Ok(()));
+    assert_eq!(
+        game.make_move(Player::Black, Move::Place(place(4, 0))),
+        Ok(())
+    );
+    assert_eq!(game.get_status(), Finished(Win(Player::Black)));
+    assert_eq!(game.count_discs(Player::Black), 5);
+}
+
+#[test]
+fn test_score() {
+    let game = Othello::new();
+
+    assert_eq!(game.get_positional_score(), 0);
+    assert_eq!(GameState::get_score(&game), 0);
+
+    let minimax = game.minimax(1).unwrap();
+
+    match minimax.outcome {
+        Indefinite(_) => (),
+        outcome => panic!("Unexpected outcome {:?}.", outcome),
+    }
+
+    assert_eq!(minimax.moves.len(), 4);
+}
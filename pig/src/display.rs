@@ -0,0 +1,38 @@
+use crate::*;
+
+use std::fmt;
+
+impl fmt::Display for Player {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Player::First => write!(f, "First player"),
+            Player::Second => write!(f, "Second player"),
+        }
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Move::Roll => write!(f, "ROLL"),
+            Move::Hold => write!(f, "HOLD"),
+            Move::Face(face) => write!(f, "{}", face),
+        }
+    }
+}
+
+impl fmt::Display for Pig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for &player in [Player::First, Player::Second].iter() {
+            writeln!(
+                f,
+                "{}: {}/{}",
+                player,
+                self.get_player_score(player),
+                self.get_target()
+            )?;
+        }
+
+        writeln!(f, "Turn total: {}", self.get_turn_total())
+    }
+}
@@ -0,0 +1,171 @@
+mod display;
+mod minimax;
+mod policy;
+
+#[cfg(test)]
+mod test;
+
+pub use crate::policy::PigPolicy;
+
+const DEFAULT_SIDES: usize = 6;
+const BUST_FACE: usize = 1;
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Player {
+    First,
+    Second,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Status {
+    Running(Player),
+    Finished(Player),
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Move {
+    Roll,
+    Hold,
+    Face(usize),
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Pig {
+    target: usize,
+    sides: usize,
+    status: Status,
+    scores: [usize; 2],
+    turn_total: usize,
+    rolling: bool,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum MoveError {
+    InvalidStatus(Status),
+    WrongPlayer(Player),
+    HoldNotAllowed,
+    RollPending,
+    NoRollPending,
+    InvalidFace(usize),
+}
+
+impl Player {
+    pub fn other(self) -> Player {
+        match self {
+            Player::First => Player::Second,
+            Player::Second => Player::First,
+        }
+    }
+
+    fn get_index(self) -> usize {
+        match self {
+            Player::First => 0,
+            Player::Second => 1,
+        }
+    }
+}
+
+impl Pig {
+    pub fn new(target: usize) -> Pig {
+        Pig::with_sides(target, DEFAULT_SIDES)
+    }
+
+    pub fn with_sides(target: usize, sides: usize) -> Pig {
+        if target == 0 {
+            panic!("The target score must be positive.");
+        } else if sides <= BUST_FACE {
+            panic!("A die with {} sides cannot be used to play Pig.", sides);
+        }
+
+        Pig {
+            target,
+            sides,
+            status: Status::Running(Player::First),
+            scores: [0; 2],
+            turn_total: 0,
+            rolling: false,
+        }
+    }
+
+    pub fn get_target(&self) -> usize {
+        self.target
+    }
+
+    pub fn get_sides(&self) -> usize {
+        self.sides
+    }
+
+    pub fn get_status(&self) -> Status {
+        self.status
+    }
+
+    pub fn get_player_score(&self, player: Player) -> usize {
+        self.scores[player.get_index()]
+    }
+
+    pub fn get_turn_total(&self) -> usize {
+        self.turn_total
+    }
+
+    pub fn is_rolling(&self) -> bool {
+        self.rolling
+    }
+
+    pub fn possible_moves(&self) -> Vec<Move> {
+        match self.status {
+            Status::Running(_) if self.rolling => (1..=self.sides).map(Move::Face).collect(),
+            Status::Running(_) if self.turn_total == 0 => vec![Move::Roll],
+            Status::Running(_) => vec![Move::Roll, Move::Hold],
+            Status::Finished(_) => Vec::new(),
+        }
+    }
+
+    fn end_turn(&mut self, player: Player) {
+        self.turn_total = 0;
+        self.status = Status::Running(player.other());
+    }
+
+    pub fn make_move(&mut self, player: Player, mov: Move) -> Result<(), MoveError> {
+        let status_player = match self.status {
+            Status::Finished(_) => return Err(MoveError::InvalidStatus(self.status)),
+            Status::Running(status_player) => status_player,
+        };
+
+        if status_player != player {
+            return Err(MoveError::WrongPlayer(status_player));
+        }
+
+        match mov {
+            Move::Roll | Move::Hold if self.rolling => return Err(MoveError::RollPending),
+            Move::Roll => self.rolling = true,
+            Move::Hold => {
+                if self.turn_total == 0 {
+                    return Err(MoveError::HoldNotAllowed);
+                }
+
+                self.scores[player.get_index()] += self.turn_total;
+                self.end_turn(player);
+            }
+            Move::Face(_) if !self.rolling => return Err(MoveError::NoRollPending),
+            Move::Face(face) if face == 0 || face > self.sides => {
+                return Err(MoveError::InvalidFace(face));
+            }
+            Move::Face(BUST_FACE) => {
+                self.rolling = false;
+                self.end_turn(player);
+            }
+            Move::Face(face) => {
+                self.rolling = false;
+                self.turn_total += face;
+
+                if self.get_player_score(player) + self.turn_total >= self.target {
+                    self.scores[player.get_index()] += self.turn_total;
+                    self.turn_total = 0;
+                    self.status = Status::Finished(player);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
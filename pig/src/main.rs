@@ -0,0 +1,177 @@
+use ::minimax::expectimax::{ExpectimaxResult, Stochastic};
+
+use std::env;
+use std::io;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use pig::*;
+
+use pig::MoveError::*;
+use pig::Status::*;
+
+enum PigError {
+    MoveError(MoveError),
+    InvalidInput(String),
+}
+
+impl From<MoveError> for PigError {
+    fn from(error: MoveError) -> PigError {
+        PigError::MoveError(error)
+    }
+}
+
+struct Die {
+    state: u64,
+    sides: usize,
+}
+
+impl Die {
+    fn new(sides: usize) -> Die {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_nanos() as u64)
+            .unwrap_or(0);
+
+        Die {
+            state: seed | 1,
+            sides,
+        }
+    }
+
+    fn roll(&mut self) -> usize {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+
+        (self.state % self.sides as u64) as usize + 1
+    }
+}
+
+fn print_instructions(game: &Pig) {
+    println!("-----------------------------");
+    println!("             PIG             ");
+    println!("-----------------------------");
+    println!();
+    println!(
+        "The first player to reach {} points wins.",
+        game.get_target()
+    );
+    println!("Enter R to roll the die or H to hold and bank the turn total.");
+    println!("Rolling a 1 loses the turn total and passes the turn.");
+    println!();
+}
+
+fn print_expectimax(game: &Pig) -> ExpectimaxResult<Pig> {
+    const EXPECTIMAX_DEPTH: usize = 6;
+
+    print!("Expectimax: ");
+
+    let expectimax = match game.expectimax(EXPECTIMAX_DEPTH) {
+        Ok(expectimax) => {
+            println!("{:.1}% for the first player", 100.0 * expectimax.value);
+            expectimax
+        }
+        Err(error) => {
+            println!("{:?}\n", error);
+            return Err(error);
+        }
+    };
+
+    let moves: Vec<String> = expectimax.moves.iter().map(|mov| mov.to_string()).collect();
+
+    println!("{}\n", moves.join(", "));
+    Ok(expectimax)
+}
+
+fn parse_input(input: String) -> Result<Move, PigError> {
+    match input.as_str() {
+        "R" => Ok(Move::Roll),
+        "H" => Ok(Move::Hold),
+        _ => Err(PigError::InvalidInput(input)),
+    }
+}
+
+fn handle_turn(game: &mut Pig, player: Player) -> Result<(), PigError> {
+    let input = {
+        let mut buffer = String::new();
+
+        io::stdin()
+            .read_line(&mut buffer)
+            .expect("It was not possible to read user input.");
+
+        println!();
+
+        buffer.trim().to_uppercase()
+    };
+
+    let mov = parse_input(input)?;
+
+    game.make_move(player, mov)?;
+    Ok(())
+}
+
+fn handle_error(error: PigError) {
+    match error {
+        PigError::MoveError(InvalidStatus(_)) => {
+            panic!("Cannot make any move now because the status of the game doesn't allow it.");
+        }
+        PigError::MoveError(WrongPlayer(_)) => {
+            panic!("Cannot make that move because it's not the player's turn.");
+        }
+        PigError::MoveError(RollPending) | PigError::MoveError(NoRollPending) => {
+            panic!("Cannot make that move because the die roll is out of order.");
+        }
+        PigError::MoveError(InvalidFace(_)) => {
+            panic!("Cannot make that move because the die doesn't have that face.");
+        }
+        PigError::MoveError(HoldNotAllowed) => {
+            println!("Cannot hold because the turn total is zero.");
+        }
+        PigError::InvalidInput(input) => {
+            println!("Invalid input: {}", input);
+            println!("Please, enter R or H.");
+        }
+    }
+
+    println!();
+}
+
+fn main() {
+    let target = env::args()
+        .skip(1)
+        .filter_map(|arg| arg.parse().ok())
+        .find(|&target| target > 0)
+        .unwrap_or(100);
+
+    let mut game = Pig::new(target);
+    let mut die = Die::new(game.get_sides());
+
+    print_instructions(&game);
+    println!("{}", game);
+
+    while let Running(player) = game.get_status() {
+        if game.is_rolling() {
+            let face = die.roll();
+            println!("{} rolled {}.\n", player, face);
+
+            game.make_move(player, Move::Face(face))
+                .expect("It was not possible to apply the die roll.");
+
+            println!("{}", game);
+            continue;
+        }
+
+        println!("{} to move.", player);
+        print_expectimax(&game).ok();
+
+        let result = handle_turn(&mut game, player);
+
+        if let Err(error) = result {
+            handle_error(error);
+        }
+    }
+
+    if let Finished(winner) = game.get_status() {
+        println!("{} wins.", winner);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::*;
+
+use ::minimax::expectimax::Stochastic;
+use ::minimax::{
+    GameResult as MinimaxGameResult, GameState, Player as MinimaxPlayer, Status as MinimaxStatus,
+};
+
+impl From<Player> for MinimaxPlayer {
+    fn from(player: Player) -> MinimaxPlayer {
+        match player {
+            Player::First => MinimaxPlayer::One,
+            Player::Second => MinimaxPlayer::Two,
+        }
+    }
+}
+
+impl From<MinimaxPlayer> for Player {
+    fn from(player: MinimaxPlayer) -> Player {
+        match player {
+            MinimaxPlayer::One => Player::First,
+            MinimaxPlayer::Two => Player::Second,
+        }
+    }
+}
+
+impl From<Status> for MinimaxStatus {
+    fn from(status: Status) -> MinimaxStatus {
+        match status {
+            Status::Running(player) => MinimaxStatus::Running(player.into()),
+            Status::Finished(winner) => {
+                MinimaxStatus::Finished(MinimaxGameResult::Win(winner.into()))
+            }
+        }
+    }
+}
+
+impl GameState for Pig {
+    type Move = Move;
+    type MoveError = MoveError;
+
+    fn get_status(&self) -> MinimaxStatus {
+        self.get_status().into()
+    }
+
+    fn possible_moves(&self) -> Vec<Move> {
+        self.possible_moves()
+    }
+
+    fn make_move(&mut self, mov: &Move) -> Result<(), MoveError> {
+        match self.status {
+            Status::Finished(_) => Err(MoveError::InvalidStatus(self.status)),
+            Status::Running(player) => self.make_move(player, *mov),
+        }
+    }
+
+    fn get_score(&self) -> isize {
+        self.get_player_score(Player::First) as isize
+            - self.get_player_score(Player::Second) as isize
+    }
+}
+
+impl Stochastic for Pig {
+    fn chance_moves(&self) -> Option<Vec<(Move, f64)>> {
+        if !self.rolling {
+            return None;
+        }
+
+        let probability = 1.0 / self.sides as f64;
+
+        Some(
+            self.possible_moves()
+                .into_iter()
+                .map(|mov| (mov, probability))
+                .collect(),
+        )
+    }
+
+    fn estimate(&self) -> f64 {
+        let score = GameState::get_score(self) as f64 / self.target as f64;
+
+        (1.0 + score) / 2.0
+    }
+}
@@ -0,0 +1,145 @@
+use crate::*;
+
+const CONVERGENCE_THRESHOLD: f64 = 1e-12;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PigPolicy {
+    target: usize,
+    sides: usize,
+    values: Vec<f64>,
+}
+
+impl PigPolicy {
+    pub fn solve(target: usize, sides: usize) -> PigPolicy {
+        let mut policy = PigPolicy {
+            target,
+            sides,
+            values: vec![0.0; target * target * target],
+        };
+
+        loop {
+            let mut max_change: f64 = 0.0;
+
+            for score in 0..target {
+                for opponent_score in 0..target {
+                    for turn_total in 0..target - score {
+                        let value = policy.get_decision_value(score, opponent_score, turn_total);
+                        let index = policy.get_index(score, opponent_score, turn_total);
+
+                        max_change = max_change.max((value - policy.values[index]).abs());
+                        policy.values[index] = value;
+                    }
+                }
+            }
+
+            if max_change < CONVERGENCE_THRESHOLD {
+                return policy;
+            }
+        }
+    }
+
+    pub fn get_target(&self) -> usize {
+        self.target
+    }
+
+    pub fn get_sides(&self) -> usize {
+        self.sides
+    }
+
+    fn get_index(&self, score: usize, opponent_score: usize, turn_total: usize) -> usize {
+        (score * self.target + opponent_score) * self.target + turn_total
+    }
+
+    fn get_value(&self, score: usize, opponent_score: usize, turn_total: usize) -> f64 {
+        self.values[self.get_index(score, opponent_score, turn_total)]
+    }
+
+    fn get_roll_value(&self, score: usize, opponent_score: usize, turn_total: usize) -> f64 {
+        let bust = 1.0 - self.get_value(opponent_score, score, 0);
+
+        let faces: f64 = (BUST_FACE + 1..=self.sides)
+            .map(|face| {
+                if score + turn_total + face >= self.target {
+                    1.0
+                } else {
+                    self.get_value(score, opponent_score, turn_total + face)
+                }
+            })
+            .sum();
+
+        (bust + faces) / self.sides as f64
+    }
+
+    fn get_hold_value(&self, score: usize, opponent_score: usize, turn_total: usize) -> f64 {
+        1.0 - self.get_value(opponent_score, score + turn_total, 0)
+    }
+
+    fn get_decision_value(&self, score: usize, opponent_score: usize, turn_total: usize) -> f64 {
+        let roll = self.get_roll_value(score, opponent_score, turn_total);
+
+        if turn_total == 0 {
+            roll
+        } else {
+            roll.max(self.get_hold_value(score, opponent_score, turn_total))
+        }
+    }
+
+    fn check_game(&self, game: &Pig) {
+        if (game.get_target(), game.get_sides()) != (self.target, self.sides) {
+            panic!(
+                "The policy for target {} with {} sides cannot be used for target {} with {} sides.",
+                self.target,
+                self.sides,
+                game.get_target(),
+                game.get_sides()
+            );
+        }
+    }
+
+    pub fn get_win_probability(&self, game: &Pig, player: Player) -> f64 {
+        self.check_game(game);
+
+        let status_player = match game.get_status() {
+            Status::Finished(winner) => return if winner == player { 1.0 } else { 0.0 },
+            Status::Running(status_player) => status_player,
+        };
+
+        let score = game.get_player_score(status_player);
+        let opponent_score = game.get_player_score(status_player.other());
+        let turn_total = game.get_turn_total();
+
+        let value = if game.is_rolling() {
+            self.get_roll_value(score, opponent_score, turn_total)
+        } else {
+            self.get_value(score, opponent_score, turn_total)
+        };
+
+        if status_player == player {
+            value
+        } else {
+            1.0 - value
+        }
+    }
+
+    pub fn get_best_move(&self, game: &Pig) -> Option<Move> {
+        self.check_game(game);
+
+        let player = match game.get_status() {
+            Status::Running(_) if game.is_rolling() => return None,
+            Status::Running(player) => player,
+            Status::Finished(_) => return None,
+        };
+
+        let score = game.get_player_score(player);
+        let opponent_score = game.get_player_score(player.other());
+        let turn_total = game.get_turn_total();
+
+        let roll = self.get_roll_value(score, opponent_score, turn_total);
+
+        if turn_total > 0 && self.get_hold_value(score, opponent_score, turn_total) > roll {
+            Some(Move::Hold)
+        } else {
+            Some(Move::Roll)
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use crate::MoveError::*;
+use crate::Status::*;
+use crate::*;
+
+use ::minimax::expectimax::{expectimax_with, Stochastic};
+
+const TOLERANCE: f64 = 1e-9;
+
+fn pig(target: usize, scores: [usize; 2], turn_total: usize, player: Player) -> Pig {
+    Pig {
+        target,
+        sides: DEFAULT_SIDES,
+        status: Running(player),
+        scores,
+        turn_total,
+        rolling: false,
+    }
+}
+
+#[test]
+fn test_pig() {
+    let mut game = Pig::new(10);
+
+    assert_eq!(game.possible_moves(), vec![Move::Roll]);
+    assert_eq!(
+        game.make_move(Player::First, Move::Hold),
+        Err(HoldNotAllowed)
+    );
+
+    assert_eq!(
+        game.make_move(Player::Second, Move::Roll),
+        Err(WrongPlayer(Player::First))
+    );
+
+    assert_eq!(
+        game.make_move(Player::First, Move::Face(3)),
+        Err(NoRollPending)
+    );
+
+    assert_eq!(game.make_move(Player::First, Move::Roll), Ok(()));
+    assert_eq!(game.possible_moves().len(), 6);
+    assert_eq!(game.chance_moves().unwrap().len(), 6);
+    assert_eq!(game.make_move(Player::First, Move::Roll), Err(RollPending));
+
+    assert_eq!(
+        game.make_move(Player::First, Move::Face(7)),
+        Err(InvalidFace(7))
+    );
+
+    assert_eq!(game.make_move(Player::First, Move::Face(4)), Ok(()));
+    assert_eq!(game.get_turn_total(), 4);
+    assert_eq!(game.possible_moves(), vec![Move::Roll, Move::Hold]);
+    assert_eq!(game.chance_moves(), None);
+
+    assert_eq!(game.make_move(Player::First, Move::Hold), Ok(()));
+    assert_eq!(game.get_player_score(Player::First), 4);
+    assert_eq!(game.get_status(), Running(Player::Second));
+
+    assert_eq!(game.make_move(Player::Second, Move::Roll), Ok(()));
+    assert_eq!(game.make_move(Player::Second, Move::Face(1)), Ok(()));
+    assert_eq!(game.get_player_score(Player::Second), 0);
+    assert_eq!(game.get_status(), Running(Player::First));
+
+    assert_eq!(game.make_move(Player::First, Move::Roll), Ok(()));
+    assert_eq!(game.make_move(Player::First, Move::Face(6)), Ok(()));
+    assert_eq!(game.get_player_score(Player::First), 10);
+    assert_eq!(game.get_status(), Finished(Player::First));
+
+    assert_eq!(
+        game.make_move(Player::Second, Move::Roll),
+        Err(InvalidStatus(Finished(Player::First)))
+    );
+}
+
+#[test]
+fn test_policy() {
+    let policy = PigPolicy::solve(10, DEFAULT_SIDES);
+    let game = Pig::new(10);
+
+    let first = policy.get_win_probability(&game, Player::First);
+    let second = policy.get_win_probability(&game, Player::Second);
+
+    assert!(first > 0.5);
+    assert!((first + second - 1.0).abs() < TOLERANCE);
+    assert_eq!(policy.get_best_move(&game), Some(Move::Roll));
+
+    let game = pig(10, [9, 0], 0, Player::First);
+    assert_eq!(policy.get_best_move(&game), Some(Move::Roll));
+
+    let game = pig(10, [0, 9], 9, Player::First);
+    assert_eq!(policy.get_best_move(&game), Some(Move::Roll));
+}
+
+#[test]
+fn test_expectimax_matches_policy() {
+    const TARGET: usize = 10;
+
+    let policy = PigPolicy::solve(TARGET, DEFAULT_SIDES);
+
+    for &player in [Player::First, Player::Second].iter() {
+        for score in 0..TARGET {
+            for opponent_score in 0..TARGET {
+                for turn_total in 0..TARGET - score {
+                    let mut scores = [opponent_score; 2];
+                    scores[player.get_index()] = score;
+
+                    let game = pig(TARGET, scores, turn_total, player);
+
+                    let expectimax = expectimax_with(&game, 1, &|state| {
+                        policy.get_win_probability(state, Player::First)
+                    })
+                    .unwrap();
+
+                    let expected = policy.get_win_probability(&game, Player::First);
+
+                    assert!((expectimax.value - expected).abs() < TOLERANCE);
+                    assert!(expectimax
+                        .moves
+                        .contains(&policy.get_best_move(&game).unwrap()));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_expectimax() {
+    let mut game = pig(10, [8, 9], 0, Player::First);
+    let bust = pig(10, [8, 9], 0, Player::Second).estimate();
+
+    let expectimax = game.expectimax(1).unwrap();
+    assert!((expectimax.value - (5.0 + bust) / 6.0).abs() < TOLERANCE);
+    assert_eq!(expectimax.moves, vec![Move::Roll]);
+
+    assert_eq!(game.make_move(Player::First, Move::Roll), Ok(()));
+
+    let expectimax = game.expectimax(0).unwrap();
+    assert!(expectimax.moves.is_empty());
+    assert!(expectimax.value > 5.0 / 6.0 - TOLERANCE);
+    assert!(expectimax.value < 1.0);
+
+    let mut game = pig(10, [9, 8], 0, Player::Second);
+    let bust = pig(10, [9, 8], 0, Player::First).estimate();
+
+    let expectimax = game.expectimax(1).unwrap();
+    assert!((expectimax.value - bust / 6.0).abs() < TOLERANCE);
+
+    assert_eq!(game.make_move(Player::Second, Move::Roll), Ok(()));
+    assert_eq!(game.make_move(Player::Second, Move::Face(2)), Ok(()));
+    assert_eq!(game.get_status(), Finished(Player::Second));
+    assert!(game.expectimax(1).is_err());
+}
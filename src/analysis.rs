@@ -0,0 +1,113 @@
+use std::hash::Hash;
+
+use crate::transposition::TranspositionTable;
+use crate::Outcome::*;
+use crate::Status::*;
+use crate::{compare_outcome, evaluate_moves, GameState, MinimaxError, MinimaxResult, Outcome};
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct MoveAnalysis<S: GameState> {
+    pub mov: S::Move,
+    pub outcome: Outcome,
+    pub line: Vec<S::Move>,
+}
+
+pub type AnalysisResult<S> = Result<Vec<MoveAnalysis<S>>, MinimaxError<S>>;
+
+pub fn analyze<S>(state: &S, depth: usize, limit: Option<usize>) -> AnalysisResult<S>
+where
+    S: GameState + Eq + Hash,
+    S::Move: Clone,
+{
+    let mut table = TranspositionTable::new();
+
+    analyze_with(state, depth, limit, &mut |child_state, depth| {
+        table.minimax(child_state, depth)
+    })
+}
+
+pub fn analyze_with<S>(
+    state: &S,
+    depth: usize,
+    limit: Option<usize>,
+    child_minimax: &mut dyn FnMut(&S, usize) -> MinimaxResult<S>,
+) -> AnalysisResult<S>
+where
+    S: GameState,
+    S::Move: Clone,
+{
+    let mut best_replies = Vec::new();
+
+    let (player, mut outcomes) = evaluate_moves(
+        state,
+        depth,
+        &mut |child_state, depth| {
+            let minimax = child_minimax(child_state, depth)?;
+            best_replies.push((child_state.clone(), minimax.moves.first().cloned()));
+            Ok(minimax)
+        },
+        &|child_state| Indefinite(child_state.get_score()),
+    )?;
+
+    if outcomes.is_empty() {
+        return Err(MinimaxError::NoPossibleMoves);
+    }
+
+    outcomes.sort_by(|(_lhs_move, lhs_outcome), (_rhs_move, rhs_outcome)| {
+        compare_outcome(player, rhs_outcome, lhs_outcome)
+    });
+
+    outcomes.truncate(limit.unwrap_or(outcomes.len()));
+
+    outcomes
+        .into_iter()
+        .map(|(mov, outcome)| {
+            let line = get_line(state, mov.clone(), depth, &best_replies, child_minimax)?;
+            Ok(MoveAnalysis { mov, outcome, line })
+        })
+        .collect()
+}
+
+fn get_line<S>(
+    state: &S,
+    mov: S::Move,
+    depth: usize,
+    best_replies: &[(S, Option<S::Move>)],
+    child_minimax: &mut dyn FnMut(&S, usize) -> MinimaxResult<S>,
+) -> Result<Vec<S::Move>, MinimaxError<S>>
+where
+    S: GameState,
+    S::Move: Clone,
+{
+    let mut state = state.clone();
+    let mut mov = mov;
+    let mut line = Vec::new();
+
+    for depth in (0..=depth).rev() {
+        state.make_move(&mov).map_err(MinimaxError::MoveError)?;
+        line.push(mov);
+
+        if depth == 0 {
+            break;
+        }
+
+        if let Finished(_) = state.get_status() {
+            break;
+        }
+
+        let best_reply = match best_replies
+            .iter()
+            .find(|(reply_state, _reply)| line.len() == 1 && *reply_state == state)
+        {
+            Some((_reply_state, reply)) => reply.clone(),
+            None => child_minimax(&state, depth - 1)?.moves.into_iter().next(),
+        };
+
+        mov = match best_reply {
+            Some(mov) => mov,
+            None => break,
+        };
+    }
+
+    Ok(line)
+}
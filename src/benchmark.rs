@@ -0,0 +1,169 @@
+use std::env;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::engine::Search;
+use crate::perft::perft;
+use crate::GameState;
+
+const DEFAULT_MIN_TIME: Duration = Duration::from_millis(500);
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct BenchmarkResult {
+    pub iterations: usize,
+    pub nodes: usize,
+    pub elapsed: Duration,
+}
+
+pub struct BenchmarkSuite {
+    filters: Vec<String>,
+    min_time: Duration,
+    total: Duration,
+}
+
+impl BenchmarkResult {
+    pub fn get_time_per_iteration(&self) -> Duration {
+        self.elapsed.div_f64(self.iterations.max(1) as f64)
+    }
+
+    pub fn get_nodes_per_second(&self) -> f64 {
+        match self.elapsed.as_secs_f64() {
+            seconds if seconds > 0.0 => self.nodes as f64 / seconds,
+            _ => 0.0,
+        }
+    }
+}
+
+pub fn run_benchmark<F: FnMut() -> usize>(min_time: Duration, mut f: F) -> BenchmarkResult {
+    let start = Instant::now();
+    let mut iterations = 0;
+    let mut nodes = 0;
+
+    loop {
+        nodes += f();
+        iterations += 1;
+
+        if start.elapsed() >= min_time {
+            break;
+        }
+    }
+
+    BenchmarkResult {
+        iterations,
+        nodes,
+        elapsed: start.elapsed(),
+    }
+}
+
+pub fn count_nodes<S: GameState>(state: &S, depth: usize) -> usize {
+    let mut search = Search::new(Arc::new(AtomicBool::new(false)), None);
+    search.minimax(state, depth).ok();
+    search.get_nodes()
+}
+
+impl BenchmarkSuite {
+    pub fn new(filters: Vec<String>, min_time: Duration) -> BenchmarkSuite {
+        BenchmarkSuite {
+            filters,
+            min_time,
+            total: Duration::default(),
+        }
+    }
+
+    pub fn from_args() -> BenchmarkSuite {
+        let filters = env::args()
+            .skip(1)
+            .filter(|arg| !arg.starts_with('-'))
+            .collect();
+
+        BenchmarkSuite::new(filters, DEFAULT_MIN_TIME)
+    }
+
+    pub fn is_selected(&self, name: &str) -> bool {
+        self.filters.is_empty()
+            || self
+                .filters
+                .iter()
+                .any(|filter| name.contains(filter.as_str()))
+    }
+
+    pub fn bench<F: FnMut() -> usize>(&mut self, name: &str, f: F) -> Option<BenchmarkResult> {
+        if !self.is_selected(name) {
+            return None;
+        }
+
+        let result = run_benchmark(self.min_time, f);
+        self.total += result.elapsed;
+
+        println!(
+            "{:<40} {:>8} iterations {:>12.3} us/iteration {:>14.0} nodes/s",
+            name,
+            result.iterations,
+            result.get_time_per_iteration().as_secs_f64() * 1_000_000.0,
+            result.get_nodes_per_second()
+        );
+
+        Some(result)
+    }
+
+    pub fn bench_minimax<S: GameState>(
+        &mut self,
+        name: &str,
+        state: &S,
+        depth: usize,
+    ) -> Option<BenchmarkResult> {
+        self.bench(name, || count_nodes(state, depth))
+    }
+
+    pub fn bench_perft<S: GameState>(
+        &mut self,
+        name: &str,
+        state: &S,
+        depth: usize,
+    ) -> Option<BenchmarkResult> {
+        self.bench(name, || {
+            perft(state, depth).map(|count| count.leaves).unwrap_or(0)
+        })
+    }
+
+    pub fn finish(self) -> Duration {
+        println!();
+        println!("total: {:.3} s", self.total.as_secs_f64());
+        self.total
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::TakeAway;
+
+    #[test]
+    fn test_benchmark() {
+        let result = BenchmarkResult {
+            iterations: 3,
+            nodes: 30,
+            elapsed: Duration::from_secs(6),
+        };
+
+        assert_eq!(result.get_time_per_iteration(), Duration::from_secs(2));
+        assert_eq!(result.get_nodes_per_second(), 5.0);
+
+        let result = BenchmarkResult {
+            iterations: usize::MAX,
+            nodes: 0,
+            elapsed: Duration::from_secs(1),
+        };
+
+        assert!(result.get_time_per_iteration() < Duration::from_nanos(1));
+
+        let mut suite = BenchmarkSuite::new(vec!["minimax".to_string()], Duration::default());
+        let game = TakeAway::new(6);
+
+        let result = suite.bench_minimax("minimax/6", &game, 3).unwrap();
+        assert_eq!(result.nodes, result.iterations * count_nodes(&game, 3));
+
+        assert_eq!(suite.bench_perft("perft/6", &game, 3), None);
+    }
+}
@@ -0,0 +1,255 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::mem;
+use std::path::Path;
+
+use crate::codec::{invalid_data, Codec};
+use crate::{GameState, Minimax, MinimaxError, Status};
+
+const MAGIC: &[u8; 4] = b"MMOB";
+
+pub struct OpeningBook<S: GameState> {
+    entries: HashMap<S, Vec<(S::Move, u32)>>,
+}
+
+impl<S> OpeningBook<S>
+where
+    S: GameState + Eq + Hash,
+    S::Move: Clone,
+{
+    pub fn new() -> OpeningBook<S> {
+        OpeningBook {
+            entries: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get(&self, state: &S) -> Option<&[(S::Move, u32)]> {
+        self.entries.get(state).map(|moves| moves.as_slice())
+    }
+
+    pub fn add(&mut self, state: &S, mov: S::Move, weight: u32) {
+        let moves = self.entries.entry(state.clone()).or_default();
+
+        match moves
+            .iter_mut()
+            .find(|(book_move, _weight)| *book_move == mov)
+        {
+            Some((_book_move, book_weight)) => *book_weight = book_weight.saturating_add(weight),
+            None => moves.push((mov, weight)),
+        }
+    }
+
+    pub fn add_minimax(&mut self, state: &S, minimax: &Minimax<S>) {
+        for mov in minimax.moves.iter() {
+            self.add(state, mov.clone(), 1);
+        }
+    }
+
+    pub fn add_game(&mut self, initial_state: &S, moves: &[S::Move]) -> Result<(), S::MoveError> {
+        let mut state = initial_state.clone();
+        let mut states = Vec::new();
+
+        for mov in moves {
+            let mut child_state = state.clone();
+            child_state.make_move(mov)?;
+            states.push(mem::replace(&mut state, child_state));
+        }
+
+        for (state, mov) in states.iter().zip(moves) {
+            self.add(state, mov.clone(), 1);
+        }
+
+        Ok(())
+    }
+
+    pub fn add_search(
+        &mut self,
+        root: &S,
+        plies: usize,
+        depth: usize,
+    ) -> Result<(), MinimaxError<S>> {
+        let mut states = vec![root.clone()];
+
+        for _ in 0..plies {
+            let mut next_states = Vec::new();
+            let mut seen_states = HashSet::new();
+
+            for state in states {
+                if let Status::Finished(_) = state.get_status() {
+                    continue;
+                }
+
+                if !self.entries.contains_key(&state) {
+                    let minimax = state.minimax(depth)?;
+                    self.add_minimax(&state, &minimax);
+                }
+
+                for (mov, _weight) in self.entries[&state].iter() {
+                    let mut child_state = state.clone();
+
+                    child_state
+                        .make_move(mov)
+                        .map_err(MinimaxError::MoveError)?;
+
+                    if seen_states.insert(child_state.clone()) {
+                        next_states.push(child_state);
+                    }
+                }
+            }
+
+            states = next_states;
+        }
+
+        Ok(())
+    }
+
+    pub fn get_best_move(&self, state: &S) -> Option<&S::Move> {
+        let moves = self.get(state)?;
+        let possible_moves: HashSet<S::Move> = state.possible_moves().into_iter().collect();
+
+        moves
+            .iter()
+            .rev()
+            .filter(|(mov, _weight)| possible_moves.contains(mov))
+            .max_by_key(|(_mov, weight)| *weight)
+            .map(|(mov, _weight)| mov)
+    }
+
+    pub fn choose_move(&self, state: &S, depth: usize) -> Result<S::Move, MinimaxError<S>> {
+        if let Some(mov) = self.get_best_move(state) {
+            return Ok(mov.clone());
+        }
+
+        state
+            .minimax(depth)?
+            .moves
+            .into_iter()
+            .next()
+            .ok_or(MinimaxError::NoPossibleMoves)
+    }
+}
+
+impl<S> OpeningBook<S>
+where
+    S: GameState + Eq + Hash + Codec,
+    S::Move: Clone + Codec,
+{
+    pub fn save<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        (self.entries.len() as u64).encode(writer)?;
+
+        for (state, moves) in self.entries.iter() {
+            state.encode(writer)?;
+            (moves.len() as u32).encode(writer)?;
+
+            for (mov, weight) in moves.iter() {
+                mov.encode(writer)?;
+                weight.encode(writer)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn load<R: Read>(reader: &mut R) -> io::Result<OpeningBook<S>> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+
+        if &magic != MAGIC {
+            return Err(invalid_data("Not an opening book file."));
+        }
+
+        let mut book = OpeningBook::new();
+
+        for _ in 0..u64::decode(reader)? {
+            let state = S::decode(reader)?;
+
+            for _ in 0..u32::decode(reader)? {
+                let mov = S::Move::decode(reader)?;
+                let weight = u32::decode(reader)?;
+                book.add(&state, mov, weight);
+            }
+        }
+
+        Ok(book)
+    }
+
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.save(&mut writer)?;
+        writer.flush()
+    }
+
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> io::Result<OpeningBook<S>> {
+        OpeningBook::load(&mut BufReader::new(File::open(path)?))
+    }
+}
+
+impl<S> Default for OpeningBook<S>
+where
+    S: GameState + Eq + Hash,
+    S::Move: Clone,
+{
+    fn default() -> OpeningBook<S> {
+        OpeningBook::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{InvalidTake, TakeAway};
+
+    #[test]
+    fn test_opening_book() {
+        let mut book = OpeningBook::new();
+
+        assert_eq!(book.add_search(&TakeAway::new(5), 3, 5), Ok(()));
+        assert_eq!(book.get(&TakeAway::new(5)), Some(&[(2, 1)][..]));
+        assert_eq!(book.get_best_move(&TakeAway::new(5)), Some(&2));
+        assert_eq!(book.choose_move(&TakeAway::new(4), 4), Ok(1));
+
+        let mut game = TakeAway::new(1);
+        game.player = game.player.other();
+
+        book.add(&game, 2, 10);
+        book.add(&game, 1, 1);
+
+        assert_eq!(book.get_best_move(&game), Some(&1));
+        assert_eq!(book.choose_move(&game, 1), Ok(1));
+
+        book.add(&game, 1, u32::MAX);
+        assert_eq!(book.get(&game), Some(&[(2, 10), (1, u32::MAX)][..]));
+
+        let len = book.len();
+
+        assert_eq!(
+            book.add_game(&TakeAway::new(4), &[1, 2, 2]),
+            Err(InvalidTake(2))
+        );
+        assert_eq!(book.len(), len);
+        assert_eq!(book.get(&TakeAway::new(4)), None);
+
+        let mut bytes = Vec::new();
+        assert!(book.save(&mut bytes).is_ok());
+
+        let loaded = OpeningBook::<TakeAway>::load(&mut bytes.as_slice()).unwrap();
+
+        assert_eq!(loaded.len(), book.len());
+        assert_eq!(loaded.get(&game), book.get(&game));
+        assert_eq!(loaded.get_best_move(&TakeAway::new(5)), Some(&2));
+        assert_eq!(loaded.get(&TakeAway::new(7)), None);
+        assert_eq!(loaded.choose_move(&TakeAway::new(7), 7), Ok(1));
+        assert!(OpeningBook::<TakeAway>::load(&mut &bytes[1..]).is_err());
+    }
+}
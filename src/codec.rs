@@ -0,0 +1,30 @@
+use std::io::{self, Read, Write};
+
+pub trait Codec: Sized {
+    fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+    fn decode<R: Read>(reader: &mut R) -> io::Result<Self>;
+}
+
+pub fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+macro_rules! impl_codec_for_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl Codec for $integer {
+                fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                    writer.write_all(&self.to_le_bytes())
+                }
+
+                fn decode<R: Read>(reader: &mut R) -> io::Result<$integer> {
+                    let mut bytes = [0; std::mem::size_of::<$integer>()];
+                    reader.read_exact(&mut bytes)?;
+                    Ok(<$integer>::from_le_bytes(bytes))
+                }
+            }
+        )*
+    };
+}
+
+impl_codec_for_integer!(u8, u16, u32, u64);
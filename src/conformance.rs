@@ -0,0 +1,163 @@
+use std::collections::HashSet;
+
+use crate::random::XorShift;
+use crate::GameState;
+use crate::Status::*;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Violation<S: GameState> {
+    NoPossibleMoves(S),
+    MovesAfterFinish(S),
+    DuplicateMove(S, S::Move),
+    RejectedMove(S, S::Move, S::MoveError),
+    NondeterministicMove(S, S::Move),
+    IllegalMoveAccepted(S, S::Move),
+    FailedMoveChangedState(S, S::Move),
+    GameTooLong(S),
+}
+
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct ConformanceReport {
+    pub games: usize,
+    pub moves: usize,
+    pub states: usize,
+}
+
+pub fn check_conformance<S>(
+    initial: &S,
+    games: usize,
+    max_moves: usize,
+    seed: u64,
+) -> Result<ConformanceReport, Violation<S>>
+where
+    S: GameState,
+    S::Move: Clone,
+{
+    let mut rng = XorShift::new(seed);
+    let mut seen_moves = HashSet::new();
+    let mut report = ConformanceReport::default();
+
+    for _ in 0..games {
+        let mut state = initial.clone();
+        let mut moves = 0;
+
+        loop {
+            let possible_moves = check_state(&state, &mut seen_moves)?;
+            report.states += 1;
+
+            if possible_moves.is_empty() {
+                break;
+            } else if moves == max_moves {
+                return Err(Violation::GameTooLong(state));
+            }
+
+            let mov = rng.choose(&possible_moves);
+
+            state
+                .make_move(&mov)
+                .map_err(|error| Violation::RejectedMove(state.clone(), mov, error))?;
+
+            moves += 1;
+        }
+
+        report.games += 1;
+        report.moves += moves;
+    }
+
+    Ok(report)
+}
+
+pub fn check_state<S>(
+    state: &S,
+    seen_moves: &mut HashSet<S::Move>,
+) -> Result<Vec<S::Move>, Violation<S>>
+where
+    S: GameState,
+    S::Move: Clone,
+{
+    let possible_moves = state.possible_moves();
+
+    match state.get_status() {
+        Running(_) if possible_moves.is_empty() => {
+            return Err(Violation::NoPossibleMoves(state.clone()));
+        }
+        Finished(_) if !possible_moves.is_empty() => {
+            return Err(Violation::MovesAfterFinish(state.clone()));
+        }
+        _ => {}
+    }
+
+    let mut unique_moves = HashSet::new();
+
+    for mov in possible_moves.iter() {
+        if !unique_moves.insert(mov) {
+            return Err(Violation::DuplicateMove(state.clone(), mov.clone()));
+        }
+
+        let mut child_state = state.clone();
+        let mut other_child_state = state.clone();
+
+        if let Err(error) = child_state.make_move(mov) {
+            return Err(Violation::RejectedMove(state.clone(), mov.clone(), error));
+        }
+
+        if other_child_state.make_move(mov).is_err() || child_state != other_child_state {
+            return Err(Violation::NondeterministicMove(state.clone(), mov.clone()));
+        }
+
+        seen_moves.insert(mov.clone());
+    }
+
+    for mov in seen_moves.iter().filter(|mov| !unique_moves.contains(mov)) {
+        let mut child_state = state.clone();
+
+        if child_state.make_move(mov).is_ok() {
+            return Err(Violation::IllegalMoveAccepted(state.clone(), mov.clone()));
+        } else if child_state != *state {
+            return Err(Violation::FailedMoveChangedState(
+                state.clone(),
+                mov.clone(),
+            ));
+        }
+    }
+
+    Ok(possible_moves)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{InvalidTake, TakeAway, UncheckedTakeAway};
+
+    #[test]
+    fn test_conformance() {
+        let report = check_conformance(&TakeAway::new(10), 50, 10, 0x7AC7).unwrap();
+
+        assert_eq!(report.games, 50);
+        assert!(report.moves >= 50 * 5 && report.moves <= 50 * 10);
+        assert_eq!(report.states, report.moves + report.games);
+
+        let report = check_conformance(&TakeAway::new(0), 10, 0, 1).unwrap();
+
+        assert_eq!(report.moves, 0);
+        assert_eq!(report.states, 10);
+
+        assert!(matches!(
+            check_conformance(&TakeAway::new(10), 1, 4, 1),
+            Err(Violation::GameTooLong(state)) if state.tokens > 0
+        ));
+
+        let mut seen_moves = [1, 2, 3].iter().cloned().collect();
+
+        assert_eq!(check_state(&TakeAway::new(1), &mut seen_moves), Ok(vec![1]));
+
+        match check_conformance(&UncheckedTakeAway(TakeAway::new(10)), 10, 10, 1) {
+            Err(Violation::RejectedMove(state, take, error)) => {
+                assert_eq!(state.0.tokens, 1);
+                assert_eq!(take, 2);
+                assert_eq!(error, InvalidTake(2));
+            }
+            result => panic!("Unexpected conformance result {:?}.", result),
+        }
+    }
+}
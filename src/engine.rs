@@ -0,0 +1,583 @@
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::io::{self, BufRead, Write};
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::notation::{InvalidNotation, Notation};
+use crate::GameResult::*;
+use crate::Outcome::*;
+use crate::{search, GameState, MinimaxError, MinimaxResult, Outcome, Player};
+
+pub struct Search {
+    nodes: usize,
+    leaves: usize,
+    deadline: Option<Instant>,
+    stop: Arc<AtomicBool>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct SearchLimits {
+    pub depth: Option<usize>,
+    pub movetime: Option<Duration>,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct SearchInfo<S: GameState> {
+    pub depth: usize,
+    pub nodes: usize,
+    pub time: Duration,
+    pub outcome: Outcome,
+    pub moves: Vec<S::Move>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum EngineCommand<S: GameState> {
+    NewGame,
+    Position(Option<S>, Vec<S::Move>),
+    Go(SearchLimits),
+    Stop,
+    IsReady,
+    Quit,
+}
+
+pub struct Engine<S: GameState> {
+    initial: S,
+    state: S,
+    max_depth: usize,
+    stop: Arc<AtomicBool>,
+    searching: Arc<AtomicBool>,
+    worker: Option<JoinHandle<io::Result<()>>>,
+}
+
+impl Search {
+    pub fn new(stop: Arc<AtomicBool>, deadline: Option<Instant>) -> Search {
+        Search {
+            nodes: 0,
+            leaves: 0,
+            deadline,
+            stop,
+        }
+    }
+
+    pub fn get_nodes(&self) -> usize {
+        self.nodes
+    }
+
+    pub fn get_leaves(&self) -> usize {
+        self.leaves
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.stop.load(AtomicOrdering::Relaxed)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    pub fn minimax<S: GameState>(&mut self, state: &S, depth: usize) -> MinimaxResult<S> {
+        if self.is_stopped() {
+            return Err(MinimaxError::Aborted);
+        }
+
+        self.nodes += 1;
+
+        let leaves = Cell::new(0);
+
+        let result = search(
+            state,
+            depth,
+            &mut |child_state, depth| self.minimax(child_state, depth),
+            &|child_state| {
+                leaves.set(leaves.get() + 1);
+                Indefinite(child_state.get_score())
+            },
+        );
+
+        self.nodes += leaves.get();
+        self.leaves += leaves.get();
+        result
+    }
+}
+
+pub fn iterative_deepening<S: GameState>(
+    state: &S,
+    limits: SearchLimits,
+    max_depth: usize,
+    stop: Arc<AtomicBool>,
+    report: &mut dyn FnMut(&SearchInfo<S>),
+) -> Result<Option<SearchInfo<S>>, MinimaxError<S>> {
+    let start = Instant::now();
+    let deadline = limits.movetime.map(|movetime| start + movetime);
+    let max_depth = limits.depth.unwrap_or(max_depth);
+
+    let mut search = Search::new(stop, deadline);
+    let mut best = None;
+
+    for depth in 0..=max_depth {
+        let leaves = search.get_leaves();
+
+        let mut minimax = match search.minimax(state, depth) {
+            Ok(minimax) => minimax,
+            Err(MinimaxError::Aborted) => break,
+            Err(error) => return Err(error),
+        };
+
+        let previous_best = best
+            .as_ref()
+            .and_then(|info: &SearchInfo<S>| info.moves.first());
+
+        if let Some(index) =
+            previous_best.and_then(|previous| minimax.moves.iter().position(|mov| mov == previous))
+        {
+            minimax.moves[..=index].rotate_right(1);
+        }
+
+        let solved = match minimax.outcome {
+            Definite(Win(_), _) => true,
+            Definite(Draw, _) => search.get_leaves() == leaves,
+            Indefinite(_) => false,
+        };
+
+        let info = SearchInfo {
+            depth,
+            nodes: search.get_nodes(),
+            time: start.elapsed(),
+            outcome: minimax.outcome,
+            moves: minimax.moves,
+        };
+
+        report(&info);
+        best = Some(info);
+
+        if solved {
+            break;
+        }
+    }
+
+    Ok(best)
+}
+
+pub fn format_outcome(outcome: &Outcome) -> String {
+    match outcome {
+        Definite(Win(Player::One), moves) => format!("win one {}", moves),
+        Definite(Win(Player::Two), moves) => format!("win two {}", moves),
+        Definite(Draw, moves) => format!("draw {}", moves),
+        Indefinite(score) => format!("value {}", score),
+    }
+}
+
+pub fn format_info<S>(info: &SearchInfo<S>) -> String
+where
+    S: GameState,
+    S::Move: Notation,
+{
+    let moves: Vec<String> = info.moves.iter().map(Notation::format).collect();
+
+    format!(
+        "info depth {} nodes {} time {} score {} moves {}",
+        info.depth,
+        info.nodes,
+        info.time.as_millis(),
+        format_outcome(&info.outcome),
+        moves.join(" ")
+    )
+}
+
+impl<S> EngineCommand<S>
+where
+    S: GameState + Notation,
+    S::Move: Notation,
+{
+    pub fn parse(input: &str) -> Result<EngineCommand<S>, InvalidNotation> {
+        let words: Vec<&str> = input.split_whitespace().collect();
+
+        match words.as_slice() {
+            ["newgame"] => Ok(EngineCommand::NewGame),
+            ["position", state, rest @ ..] => {
+                let state = match *state {
+                    "startpos" => None,
+                    state => Some(S::parse(state)?),
+                };
+
+                let moves = match rest {
+                    [] => Vec::new(),
+                    ["moves", moves @ ..] => moves
+                        .iter()
+                        .map(|mov| S::Move::parse(mov))
+                        .collect::<Result<_, _>>()?,
+                    _ => return Err(InvalidNotation),
+                };
+
+                Ok(EngineCommand::Position(state, moves))
+            }
+            ["go", rest @ ..] => parse_limits(rest).map(EngineCommand::Go),
+            ["stop"] => Ok(EngineCommand::Stop),
+            ["isready"] => Ok(EngineCommand::IsReady),
+            ["quit"] => Ok(EngineCommand::Quit),
+            _ => Err(InvalidNotation),
+        }
+    }
+}
+
+fn parse_limits(words: &[&str]) -> Result<SearchLimits, InvalidNotation> {
+    let mut limits = SearchLimits::default();
+
+    for pair in words.chunks(2) {
+        let value = match pair {
+            [_key, value] => value.parse::<u64>().map_err(|_| InvalidNotation)?,
+            _ => return Err(InvalidNotation),
+        };
+
+        match pair[0] {
+            "depth" => limits.depth = Some(value as usize),
+            "movetime" => limits.movetime = Some(Duration::from_millis(value)),
+            _ => return Err(InvalidNotation),
+        }
+    }
+
+    Ok(limits)
+}
+
+fn lock<W>(output: &Mutex<W>) -> MutexGuard<'_, W> {
+    output.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl<S> Engine<S>
+where
+    S: GameState + Notation + Send + 'static,
+    S::Move: Notation + Clone + Send,
+    S::MoveError: Debug,
+{
+    pub fn new(initial: S, max_depth: usize) -> Engine<S> {
+        Engine {
+            state: initial.clone(),
+            initial,
+            max_depth,
+            stop: Arc::new(AtomicBool::new(false)),
+            searching: Arc::new(AtomicBool::new(false)),
+            worker: None,
+        }
+    }
+
+    pub fn get_state(&self) -> &S {
+        &self.state
+    }
+
+    pub fn run<R, W>(&mut self, input: R, output: W) -> io::Result<W>
+    where
+        R: BufRead,
+        W: Write + Send + 'static,
+    {
+        let output = Arc::new(Mutex::new(output));
+
+        for line in input.lines() {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            match EngineCommand::parse(&line) {
+                Ok(EngineCommand::Stop) => self.stop()?,
+                Ok(EngineCommand::Quit) => break,
+                Ok(EngineCommand::IsReady) => self.execute(EngineCommand::IsReady, &output)?,
+                Ok(_command) if self.is_searching()? => {
+                    let mut output = lock(&output);
+                    writeln!(output, "error search in progress: {}", line.trim())?;
+                    output.flush()?;
+                }
+                Ok(command) => self.execute(command, &output)?,
+                Err(InvalidNotation) => {
+                    let mut output = lock(&output);
+                    writeln!(output, "error invalid command: {}", line.trim())?;
+                    output.flush()?;
+                }
+            }
+        }
+
+        self.stop()?;
+
+        match Arc::try_unwrap(output) {
+            Ok(output) => Ok(output.into_inner().unwrap_or_else(PoisonError::into_inner)),
+            Err(_output) => panic!("The engine output is still in use."),
+        }
+    }
+
+    pub fn execute<W>(
+        &mut self,
+        command: EngineCommand<S>,
+        output: &Arc<Mutex<W>>,
+    ) -> io::Result<()>
+    where
+        W: Write + Send + 'static,
+    {
+        match command {
+            EngineCommand::NewGame => {
+                self.state = self.initial.clone();
+                Ok(())
+            }
+            EngineCommand::Position(state, moves) => {
+                let mut state = state.unwrap_or_else(|| self.initial.clone());
+
+                for mov in moves.iter() {
+                    if let Err(error) = state.make_move(mov) {
+                        let mut output = lock(output);
+                        writeln!(output, "error illegal move {}: {:?}", mov.format(), error)?;
+                        return output.flush();
+                    }
+                }
+
+                self.state = state;
+                Ok(())
+            }
+            EngineCommand::Go(limits) => {
+                self.wait()?;
+                self.go(limits, output);
+                Ok(())
+            }
+            EngineCommand::Stop => self.stop(),
+            EngineCommand::IsReady => {
+                let mut output = lock(output);
+                writeln!(output, "readyok")?;
+                output.flush()
+            }
+            EngineCommand::Quit => self.stop(),
+        }
+    }
+
+    fn go<W>(&mut self, limits: SearchLimits, output: &Arc<Mutex<W>>)
+    where
+        W: Write + Send + 'static,
+    {
+        let state = self.state.clone();
+        let max_depth = self.max_depth;
+        let stop = Arc::clone(&self.stop);
+        let searching = Arc::clone(&self.searching);
+        let output = Arc::clone(output);
+
+        stop.store(false, AtomicOrdering::Relaxed);
+        searching.store(true, AtomicOrdering::Relaxed);
+
+        self.worker = Some(thread::spawn(move || {
+            let mut report_result = Ok(());
+
+            let result = iterative_deepening(&state, limits, max_depth, stop, &mut |info| {
+                let mut output = lock(&output);
+
+                if report_result.is_ok() {
+                    report_result =
+                        writeln!(output, "{}", format_info(info)).and_then(|()| output.flush());
+                }
+            });
+
+            searching.store(false, AtomicOrdering::Relaxed);
+            report_result?;
+
+            let mut output = lock(&output);
+
+            match result {
+                Ok(Some(info)) => match info.moves.first() {
+                    Some(mov) => writeln!(output, "bestmove {}", mov.format())?,
+                    None => writeln!(output, "bestmove none")?,
+                },
+                Ok(None) => match state.possible_moves().first() {
+                    Some(mov) => writeln!(output, "bestmove {}", mov.format())?,
+                    None => writeln!(output, "bestmove none")?,
+                },
+                Err(MinimaxError::GameAlreadyFinished) | Err(MinimaxError::NoPossibleMoves) => {
+                    writeln!(output, "bestmove none")?
+                }
+                Err(MinimaxError::MoveError(error)) => {
+                    writeln!(output, "error search failed: {:?}", error)?
+                }
+                Err(MinimaxError::UnlistedMove(mov)) => writeln!(
+                    output,
+                    "error search failed: unlisted move {}",
+                    mov.format()
+                )?,
+                Err(MinimaxError::Aborted) => writeln!(output, "bestmove none")?,
+            }
+
+            output.flush()
+        }));
+    }
+
+    fn is_searching(&mut self) -> io::Result<bool> {
+        if self.searching.load(AtomicOrdering::Relaxed) {
+            return Ok(true);
+        }
+
+        self.wait().map(|()| false)
+    }
+
+    fn wait(&mut self) -> io::Result<()> {
+        match self.worker.take() {
+            Some(worker) => worker.join().expect("The search thread panicked."),
+            None => Ok(()),
+        }
+    }
+
+    fn stop(&mut self) -> io::Result<()> {
+        self.stop.store(true, AtomicOrdering::Relaxed);
+        self.wait()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::TakeAway;
+
+    use std::io::BufReader;
+
+    #[test]
+    fn test_engine() {
+        assert_eq!(
+            EngineCommand::<TakeAway>::parse("position startpos moves 1 2"),
+            Ok(EngineCommand::Position(None, vec![1, 2]))
+        );
+        assert_eq!(
+            EngineCommand::parse("position 4:2 moves 1"),
+            Ok(EngineCommand::Position(
+                Some(TakeAway {
+                    tokens: 4,
+                    player: Player::Two,
+                }),
+                vec![1]
+            ))
+        );
+        assert_eq!(
+            EngineCommand::<TakeAway>::parse("go depth 3 movetime 100"),
+            Ok(EngineCommand::Go(SearchLimits {
+                depth: Some(3),
+                movetime: Some(Duration::from_millis(100)),
+            }))
+        );
+        assert_eq!(
+            EngineCommand::<TakeAway>::parse("go"),
+            Ok(EngineCommand::Go(SearchLimits::default()))
+        );
+
+        let invalid = [
+            "go depth",
+            "go depth x",
+            "go nodes 3",
+            "position",
+            "position 4:3",
+            "position startpos 1",
+            "position startpos moves x",
+            "stop now",
+        ];
+
+        for input in invalid.iter() {
+            assert_eq!(
+                EngineCommand::<TakeAway>::parse(input),
+                Err(InvalidNotation)
+            );
+        }
+
+        let mut search = Search::new(Arc::new(AtomicBool::new(false)), None);
+        let game = TakeAway::new(7);
+
+        assert_eq!(search.minimax(&game, 4), game.minimax(4));
+        assert!(search.get_nodes() > search.get_leaves());
+        assert!(search.get_leaves() > 0);
+
+        let mut stopped = Search::new(Arc::new(AtomicBool::new(true)), None);
+        assert_eq!(stopped.minimax(&game, 4), Err(MinimaxError::Aborted));
+        assert_eq!(stopped.get_nodes(), 0);
+
+        let mut infos = Vec::new();
+        let best = iterative_deepening(
+            &TakeAway::new(4),
+            SearchLimits::default(),
+            10,
+            Arc::new(AtomicBool::new(false)),
+            &mut |info| infos.push(info.clone()),
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(infos.len(), 3);
+        assert_eq!(best.depth, 2);
+        assert_eq!(best.outcome, Definite(Win(Player::One), 2));
+        assert_eq!(best.moves, vec![1]);
+
+        let (input_reader, mut input) = io::pipe().unwrap();
+        let (output_reader, output_writer) = io::pipe().unwrap();
+
+        let worker = thread::spawn(move || {
+            let mut engine = Engine::new(TakeAway::new(200), 200);
+            let result = engine.run(BufReader::new(input_reader), output_writer);
+            result.map(|_output| *engine.get_state())
+        });
+
+        let mut output = BufReader::new(output_reader).lines();
+
+        let mut send = |command: &str| writeln!(input, "{}", command).unwrap();
+        let mut receive = |infos: &mut Vec<String>| loop {
+            let line = output.next().unwrap().unwrap();
+
+            if !line.starts_with("info ") {
+                return line;
+            }
+
+            infos.push(line);
+        };
+
+        let mut infos = Vec::new();
+
+        send("isready");
+        assert_eq!(receive(&mut infos), "readyok");
+
+        send("position startpos moves 2 3");
+        assert_eq!(receive(&mut infos), "error illegal move 3: InvalidTake(3)");
+
+        send("position 4:1");
+        send("go depth 10");
+        assert_eq!(receive(&mut infos), "bestmove 1");
+
+        assert_eq!(infos.len(), 3);
+        assert!(infos[0].starts_with("info depth 0 nodes "));
+        assert!(infos[2].starts_with("info depth 2 nodes "));
+        assert!(infos[2].ends_with(" score win one 2 moves 1"));
+
+        send("newgame");
+        send("go");
+        send("isready");
+        assert_eq!(receive(&mut infos), "readyok");
+
+        send("position startpos moves 2 2");
+        assert_eq!(
+            receive(&mut infos),
+            "error search in progress: position startpos moves 2 2"
+        );
+
+        send("stop");
+        assert!(receive(&mut infos).starts_with("bestmove "));
+
+        send("position 0:2");
+        send("go");
+        assert_eq!(receive(&mut infos), "bestmove none");
+
+        send("foo");
+        assert_eq!(receive(&mut infos), "error invalid command: foo");
+
+        send("position startpos moves 2 2");
+        send("isready");
+        assert_eq!(receive(&mut infos), "readyok");
+
+        drop(input);
+
+        assert_eq!(
+            worker.join().unwrap().unwrap(),
+            TakeAway {
+                tokens: 196,
+                player: Player::One,
+            }
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use crate::GameResult::*;
+use crate::Status::*;
+use crate::{GameResult, GameState, MinimaxError, Player};
+
+const EPSILON: f64 = 1e-9;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Expectimax<S: GameState> {
+    pub value: f64,
+    pub moves: Vec<S::Move>,
+}
+
+pub type ExpectimaxResult<S> = Result<Expectimax<S>, MinimaxError<S>>;
+
+pub trait Stochastic: GameState {
+    fn chance_moves(&self) -> Option<Vec<(Self::Move, f64)>>;
+
+    fn estimate(&self) -> f64 {
+        0.5
+    }
+
+    fn expectimax(&self, depth: usize) -> ExpectimaxResult<Self> {
+        expectimax_with(self, depth, &|state: &Self| state.estimate())
+    }
+}
+
+pub fn get_result_value(result: GameResult) -> f64 {
+    match result {
+        Win(Player::One) => 1.0,
+        Win(Player::Two) => 0.0,
+        Draw => 0.5,
+    }
+}
+
+pub fn expectimax_with<S: Stochastic>(
+    state: &S,
+    depth: usize,
+    leaf_value: &dyn Fn(&S) -> f64,
+) -> ExpectimaxResult<S> {
+    let player = match state.get_status() {
+        Running(player) => player,
+        Finished(_) => return Err(MinimaxError::GameAlreadyFinished),
+    };
+
+    let child_value = |mov: &S::Move| -> Result<f64, MinimaxError<S>> {
+        let mut child_state = state.clone();
+
+        child_state
+            .make_move(mov)
+            .map_err(MinimaxError::MoveError)?;
+
+        match child_state.get_status() {
+            Finished(result) => Ok(get_result_value(result)),
+            Running(_) if depth == 0 => Ok(leaf_value(&child_state)),
+            _ => Ok(expectimax_with(&child_state, depth - 1, leaf_value)?.value),
+        }
+    };
+
+    if let Some(chances) = state.chance_moves() {
+        if chances.is_empty() {
+            return Err(MinimaxError::NoPossibleMoves);
+        }
+
+        let mut value = 0.0;
+
+        for (mov, probability) in chances.iter() {
+            value += probability * child_value(mov)?;
+        }
+
+        return Ok(Expectimax {
+            value,
+            moves: Vec::new(),
+        });
+    }
+
+    let values = state
+        .possible_moves()
+        .into_iter()
+        .map(|mov| child_value(&mov).map(|value| (mov, value)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let normalize = |value: f64| match player {
+        Player::One => value,
+        Player::Two => -value,
+    };
+
+    let best_value = values
+        .iter()
+        .map(|&(_, value)| value)
+        .max_by(|&lhs, &rhs| normalize(lhs).total_cmp(&normalize(rhs)))
+        .ok_or(MinimaxError::NoPossibleMoves)?;
+
+    let moves = values
+        .into_iter()
+        .filter(|&(_, value)| (value - best_value).abs() < EPSILON)
+        .map(|(mov, _)| mov)
+        .collect();
+
+    Ok(Expectimax {
+        value: best_value,
+        moves,
+    })
+}
@@ -1,3 +1,22 @@
+pub mod analysis;
+pub mod benchmark;
+pub mod book;
+pub mod codec;
+pub mod conformance;
+pub mod engine;
+pub mod expectimax;
+pub mod notation;
+pub mod perft;
+pub mod random;
+pub mod repl;
+pub mod review;
+pub mod symmetry;
+pub mod tablebase;
+pub mod transposition;
+
+#[cfg(test)]
+mod test;
+
 use std::cmp::Ordering;
 use std::hash::Hash;
 
@@ -40,10 +59,14 @@ pub enum MinimaxError<S: GameState> {
     GameAlreadyFinished,
     MoveError(S::MoveError),
     NoPossibleMoves,
+    UnlistedMove(S::Move),
+    Aborted,
 }
 
 pub type MinimaxResult<S> = Result<Minimax<S>, MinimaxError<S>>;
 
+type MoveOutcomes<S> = Vec<(<S as GameState>::Move, Outcome)>;
+
 pub trait GameState: Clone + PartialEq {
     type Move: Eq + Hash;
     type MoveError;
@@ -57,55 +80,8 @@ pub trait GameState: Clone + PartialEq {
     }
 
     fn minimax(&self, depth: usize) -> MinimaxResult<Self> {
-        let player = match self.get_status() {
-            Running(player) => player,
-            Finished(_) => return Err(MinimaxError::GameAlreadyFinished),
-        };
-
-        let outcomes = self
-            .possible_moves()
-            .into_iter()
-            .map(|mov| {
-                let mut child_state = self.clone();
-
-                child_state
-                    .make_move(&mov)
-                    .map_err(MinimaxError::MoveError)?;
-
-                let outcome = match child_state.get_status() {
-                    Status::Finished(result) => Definite(result, 0),
-                    Status::Running(_) if depth == 0 => Indefinite(child_state.get_score()),
-                    _ => {
-                        let child_outcome = child_state.minimax(depth - 1)?.outcome;
-
-                        match child_outcome {
-                            Definite(result, moves) => Definite(result, moves + 1),
-                            _ => child_outcome,
-                        }
-                    }
-                };
-
-                Ok((mov, outcome))
-            })
-            .collect::<Result<Vec<_>, _>>()?;
-
-        let best_outcome = outcomes
-            .iter()
-            .max_by(|(_lhs_move, lhs_outcome), (_rhs_move, rhs_outcome)| {
-                compare_outcome(player, lhs_outcome, rhs_outcome)
-            })
-            .ok_or(MinimaxError::NoPossibleMoves)?
-            .1;
-
-        let moves = outcomes
-            .into_iter()
-            .filter(|(_mov, outcome)| *outcome == best_outcome)
-            .map(|(mov, _outcome)| mov)
-            .collect();
-
-        Ok(Minimax {
-            outcome: best_outcome,
-            moves,
+        minimax_with(self, depth, &mut |child_state, depth| {
+            child_state.minimax(depth)
         })
     }
 }
@@ -128,7 +104,86 @@ impl GameResult {
     }
 }
 
-fn compare_outcome(player: Player, lhs: &Outcome, rhs: &Outcome) -> Ordering {
+pub(crate) fn evaluate_moves<S: GameState>(
+    state: &S,
+    depth: usize,
+    child_minimax: &mut dyn FnMut(&S, usize) -> MinimaxResult<S>,
+    leaf_outcome: &dyn Fn(&S) -> Outcome,
+) -> Result<(Player, MoveOutcomes<S>), MinimaxError<S>> {
+    let player = match state.get_status() {
+        Running(player) => player,
+        Finished(_) => return Err(MinimaxError::GameAlreadyFinished),
+    };
+
+    let outcomes = state
+        .possible_moves()
+        .into_iter()
+        .map(|mov| {
+            let mut child_state = state.clone();
+
+            child_state
+                .make_move(&mov)
+                .map_err(MinimaxError::MoveError)?;
+
+            let outcome = match child_state.get_status() {
+                Status::Finished(result) => Definite(result, 0),
+                Status::Running(_) if depth == 0 => leaf_outcome(&child_state),
+                _ => {
+                    let child_outcome = child_minimax(&child_state, depth - 1)?.outcome;
+
+                    match child_outcome {
+                        Definite(result, moves) => Definite(result, moves + 1),
+                        _ => child_outcome,
+                    }
+                }
+            };
+
+            Ok((mov, outcome))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((player, outcomes))
+}
+
+pub(crate) fn search<S: GameState>(
+    state: &S,
+    depth: usize,
+    child_minimax: &mut dyn FnMut(&S, usize) -> MinimaxResult<S>,
+    leaf_outcome: &dyn Fn(&S) -> Outcome,
+) -> MinimaxResult<S> {
+    let (player, outcomes) = evaluate_moves(state, depth, child_minimax, leaf_outcome)?;
+
+    let best_outcome = outcomes
+        .iter()
+        .max_by(|(_lhs_move, lhs_outcome), (_rhs_move, rhs_outcome)| {
+            compare_outcome(player, lhs_outcome, rhs_outcome)
+        })
+        .ok_or(MinimaxError::NoPossibleMoves)?
+        .1;
+
+    let moves = outcomes
+        .into_iter()
+        .filter(|(_mov, outcome)| *outcome == best_outcome)
+        .map(|(mov, _outcome)| mov)
+        .collect();
+
+    Ok(Minimax {
+        outcome: best_outcome,
+        moves,
+    })
+}
+
+pub fn minimax_with<S: GameState>(
+    state: &S,
+    depth: usize,
+    child_minimax: &mut dyn FnMut(&S, usize) -> MinimaxResult<S>,
+) -> MinimaxResult<S> {
+    search(state, depth, child_minimax, &|child_state| {
+        Indefinite(child_state.get_score())
+    })
+}
+
+pub fn compare_outcome(player: Player, lhs: &Outcome, rhs: &Outcome) -> Ordering {
     if lhs == rhs {
         return Ordering::Equal;
     }
@@ -0,0 +1,7 @@
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct InvalidNotation;
+
+pub trait Notation: Sized {
+    fn format(&self) -> String;
+    fn parse(input: &str) -> Result<Self, InvalidNotation>;
+}
@@ -0,0 +1,258 @@
+use std::fmt::Debug;
+use std::io::{self, Write};
+use std::ops::AddAssign;
+
+use crate::notation::Notation;
+use crate::GameState;
+use crate::Status::*;
+
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct PerftCount {
+    pub leaves: usize,
+    pub finished: usize,
+}
+
+pub type DivideResult<S> =
+    Result<Vec<(<S as GameState>::Move, PerftCount)>, <S as GameState>::MoveError>;
+
+impl AddAssign for PerftCount {
+    fn add_assign(&mut self, other: PerftCount) {
+        self.leaves += other.leaves;
+        self.finished += other.finished;
+    }
+}
+
+pub fn perft<S: GameState>(state: &S, depth: usize) -> Result<PerftCount, S::MoveError> {
+    match state.get_status() {
+        Finished(_) => {
+            return Ok(PerftCount {
+                leaves: 1,
+                finished: 1,
+            })
+        }
+        Running(_) if depth == 0 => {
+            return Ok(PerftCount {
+                leaves: 1,
+                finished: 0,
+            })
+        }
+        Running(_) => {}
+    }
+
+    let possible_moves = state.possible_moves();
+
+    if possible_moves.is_empty() {
+        return Ok(PerftCount {
+            leaves: 1,
+            finished: 0,
+        });
+    }
+
+    let mut count = PerftCount::default();
+
+    for mov in possible_moves.iter() {
+        let mut child_state = state.clone();
+        child_state.make_move(mov)?;
+        count += perft(&child_state, depth - 1)?;
+    }
+
+    Ok(count)
+}
+
+pub fn divide<S: GameState>(state: &S, depth: usize) -> DivideResult<S> {
+    if depth == 0 {
+        return Ok(Vec::new());
+    }
+
+    state
+        .possible_moves()
+        .into_iter()
+        .map(|mov| {
+            let mut child_state = state.clone();
+            child_state.make_move(&mov)?;
+
+            let count = perft(&child_state, depth - 1)?;
+            Ok((mov, count))
+        })
+        .collect()
+}
+
+fn count_by_depth<S: GameState>(state: &S, depth: usize) -> Result<Vec<PerftCount>, S::MoveError> {
+    let leaf = PerftCount {
+        leaves: 1,
+        finished: 0,
+    };
+
+    let possible_moves = match state.get_status() {
+        Finished(_) => {
+            return Ok(vec![
+                PerftCount {
+                    leaves: 1,
+                    finished: 1,
+                };
+                depth + 1
+            ])
+        }
+        Running(_) if depth == 0 => return Ok(vec![leaf]),
+        Running(_) => state.possible_moves(),
+    };
+
+    if possible_moves.is_empty() {
+        return Ok(vec![leaf; depth + 1]);
+    }
+
+    let mut counts = vec![PerftCount::default(); depth + 1];
+    counts[0] = leaf;
+
+    for mov in possible_moves.iter() {
+        let mut child_state = state.clone();
+        child_state.make_move(mov)?;
+
+        for (count, child_count) in counts[1..]
+            .iter_mut()
+            .zip(count_by_depth(&child_state, depth - 1)?)
+        {
+            *count += child_count;
+        }
+    }
+
+    Ok(counts)
+}
+
+pub fn print_perft<S, W>(state: &S, depth: usize, output: &mut W) -> io::Result<()>
+where
+    S: GameState,
+    S::Move: Notation,
+    S::MoveError: Debug,
+    W: Write,
+{
+    let move_error = |error| io::Error::other(format!("Move error: {:?}", error));
+
+    let moves = match depth {
+        0 => Vec::new(),
+        _ => state
+            .possible_moves()
+            .into_iter()
+            .map(|mov| {
+                let mut child_state = state.clone();
+                child_state.make_move(&mov)?;
+                Ok((mov, count_by_depth(&child_state, depth - 1)?))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(move_error)?,
+    };
+
+    let counts = if moves.is_empty() {
+        count_by_depth(state, depth).map_err(move_error)?[1..].to_vec()
+    } else {
+        (0..depth)
+            .map(|i| {
+                let mut count = PerftCount::default();
+
+                for (_mov, child_counts) in moves.iter() {
+                    count += child_counts[i];
+                }
+
+                count
+            })
+            .collect()
+    };
+
+    for (i, count) in counts.iter().enumerate() {
+        writeln!(
+            output,
+            "depth {}: {} leaves, {} finished",
+            i + 1,
+            count.leaves,
+            count.finished
+        )?;
+    }
+
+    writeln!(output)?;
+
+    let mut total = PerftCount::default();
+
+    for (mov, child_counts) in moves {
+        writeln!(
+            output,
+            "{}: {}",
+            mov.format(),
+            child_counts[depth - 1].leaves
+        )?;
+        total += child_counts[depth - 1];
+    }
+
+    writeln!(output)?;
+    writeln!(output, "total: {}", total.leaves)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{InvalidTake, TakeAway, UncheckedTakeAway};
+
+    #[test]
+    fn test_perft() {
+        let game = TakeAway::new(3);
+
+        let counts: Vec<(usize, usize)> = (0..=4)
+            .map(|depth| perft(&game, depth).unwrap())
+            .map(|count| (count.leaves, count.finished))
+            .collect();
+
+        assert_eq!(counts, vec![(1, 0), (2, 0), (3, 2), (3, 3), (3, 3)]);
+
+        assert_eq!(
+            divide(&game, 3),
+            Ok(vec![
+                (
+                    1,
+                    PerftCount {
+                        leaves: 2,
+                        finished: 2
+                    }
+                ),
+                (
+                    2,
+                    PerftCount {
+                        leaves: 1,
+                        finished: 1
+                    }
+                ),
+            ])
+        );
+        assert_eq!(divide(&game, 0), Ok(Vec::new()));
+
+        let finished = TakeAway::new(0);
+
+        assert_eq!(
+            perft(&finished, 3),
+            Ok(PerftCount {
+                leaves: 1,
+                finished: 1
+            })
+        );
+        assert_eq!(divide(&finished, 3), Ok(Vec::new()));
+
+        let mut output = Vec::new();
+        print_perft(&game, 2, &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "depth 1: 2 leaves, 0 finished\n\
+             depth 2: 3 leaves, 2 finished\n\
+             \n\
+             1: 2\n\
+             2: 1\n\
+             \n\
+             total: 3\n"
+        );
+
+        let mut output = Vec::new();
+        let unchecked = UncheckedTakeAway(TakeAway::new(1));
+
+        assert_eq!(perft(&unchecked, 2), Err(InvalidTake(2)));
+        assert!(print_perft(&unchecked, 2, &mut output).is_err());
+        assert!(output.is_empty());
+    }
+}
@@ -0,0 +1,19 @@
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct XorShift(u64);
+
+impl XorShift {
+    pub fn new(seed: u64) -> XorShift {
+        XorShift(seed | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    pub fn choose<T: Clone>(&mut self, values: &[T]) -> T {
+        values[self.next_u64() as usize % values.len()].clone()
+    }
+}
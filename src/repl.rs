@@ -0,0 +1,378 @@
+use std::fmt::{Debug, Display};
+use std::io::{self, BufRead, Write};
+
+use crate::analysis::analyze_with;
+use crate::notation::{InvalidNotation, Notation};
+use crate::review::{review_game_with, OutcomeClass};
+use crate::GameResult::*;
+use crate::Status::*;
+use crate::{GameState, MinimaxError, MinimaxResult, Player};
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Command<M> {
+    Move(M),
+    Hint,
+    Undo,
+    Analyze,
+    Play(Option<usize>),
+    Board,
+    Review,
+    Help,
+    Quit,
+}
+
+pub trait Sides {
+    type Side: Debug;
+
+    fn get_side(player: Player) -> Self::Side;
+}
+
+type SearchFn<S> = dyn FnMut(&S, usize) -> MinimaxResult<S>;
+
+pub struct Repl<S: GameState> {
+    state: S,
+    history: Vec<(S, S::Move)>,
+    depth: usize,
+    search: Box<SearchFn<S>>,
+}
+
+impl<M: Notation> Command<M> {
+    pub fn parse(input: &str) -> Result<Command<M>, InvalidNotation> {
+        let mut words = input.split_whitespace();
+
+        let command = match words.next() {
+            Some(word) => word.to_lowercase(),
+            None => return Err(InvalidNotation),
+        };
+
+        let argument = words.next();
+
+        if words.next().is_some() {
+            return M::parse(input).map(Command::Move);
+        }
+
+        match (command.as_str(), argument) {
+            ("hint", None) => Ok(Command::Hint),
+            ("undo", None) => Ok(Command::Undo),
+            ("analyze", None) => Ok(Command::Analyze),
+            ("play", None) => Ok(Command::Play(None)),
+            ("play", Some(depth)) => depth
+                .parse()
+                .map(|depth| Command::Play(Some(depth)))
+                .map_err(|_| InvalidNotation),
+            ("board", None) => Ok(Command::Board),
+            ("review", None) => Ok(Command::Review),
+            ("help", None) => Ok(Command::Help),
+            ("quit", None) => Ok(Command::Quit),
+            _ => M::parse(input).map(Command::Move),
+        }
+    }
+}
+
+impl<S> Repl<S>
+where
+    S: GameState + Sides + Display + 'static,
+    S::Move: Notation + Clone,
+    S::MoveError: Debug,
+{
+    pub fn new(state: S, depth: usize) -> Repl<S> {
+        Repl::with_search(state, depth, |state, depth| state.minimax(depth))
+    }
+
+    pub fn with_search<F>(state: S, depth: usize, search: F) -> Repl<S>
+    where
+        F: FnMut(&S, usize) -> MinimaxResult<S> + 'static,
+    {
+        Repl {
+            state,
+            history: Vec::new(),
+            depth,
+            search: Box::new(search),
+        }
+    }
+
+    pub fn get_state(&self) -> &S {
+        &self.state
+    }
+
+    pub fn get_moves(&self) -> Vec<S::Move> {
+        self.history
+            .iter()
+            .map(|(_state, mov)| mov.clone())
+            .collect()
+    }
+
+    pub fn run<R: BufRead, W: Write>(&mut self, input: R, output: &mut W) -> io::Result<()> {
+        self.print_help(output)?;
+        self.print_board(output)?;
+
+        for line in input.lines() {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            match Command::parse(&line) {
+                Ok(Command::Quit) => break,
+                Ok(command) => self.execute(command, output)?,
+                Err(InvalidNotation) => {
+                    writeln!(output, "Invalid input: {}", line.trim())?;
+                    writeln!(output, "Enter \"help\" to list the available commands.")?;
+                    writeln!(output)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn execute<W: Write>(
+        &mut self,
+        command: Command<S::Move>,
+        output: &mut W,
+    ) -> io::Result<()> {
+        match command {
+            Command::Move(mov) => self.play_move(mov, output),
+            Command::Hint => self.print_hint(output),
+            Command::Undo => self.undo(output),
+            Command::Analyze => self.print_analysis(output),
+            Command::Play(depth) => self.play_best_move(depth.unwrap_or(self.depth), output),
+            Command::Board => self.print_board(output),
+            Command::Review => self.print_review(output),
+            Command::Help => self.print_help(output),
+            Command::Quit => Ok(()),
+        }
+    }
+
+    fn play_move<W: Write>(&mut self, mov: S::Move, output: &mut W) -> io::Result<()> {
+        let previous_state = self.state.clone();
+
+        match self.state.make_move(&mov) {
+            Ok(()) => {
+                self.history.push((previous_state, mov));
+                self.print_board(output)
+            }
+            Err(error) => {
+                writeln!(output, "Cannot make that move: {:?}", error)?;
+                writeln!(output)
+            }
+        }
+    }
+
+    fn play_best_move<W: Write>(&mut self, depth: usize, output: &mut W) -> io::Result<()> {
+        let minimax = match (self.search)(&self.state, depth) {
+            Ok(minimax) => minimax,
+            Err(error) => return print_error(error, output),
+        };
+
+        match minimax.moves.first() {
+            Some(mov) => {
+                writeln!(output, "Playing {}: {:?}", mov.format(), minimax.outcome)?;
+                writeln!(output)?;
+                self.play_move(mov.clone(), output)
+            }
+            None => print_error(MinimaxError::<S>::NoPossibleMoves, output),
+        }
+    }
+
+    fn undo<W: Write>(&mut self, output: &mut W) -> io::Result<()> {
+        match self.history.pop() {
+            Some((state, _mov)) => {
+                self.state = state;
+                self.print_board(output)
+            }
+            None => {
+                writeln!(output, "There are no moves to undo.")?;
+                writeln!(output)
+            }
+        }
+    }
+
+    fn print_hint<W: Write>(&mut self, output: &mut W) -> io::Result<()> {
+        let minimax = match (self.search)(&self.state, self.depth) {
+            Ok(minimax) => minimax,
+            Err(error) => return print_error(error, output),
+        };
+
+        let moves: Vec<String> = minimax.moves.iter().map(Notation::format).collect();
+
+        writeln!(
+            output,
+            "Hint: {} ({:?})",
+            moves.join(" | "),
+            minimax.outcome
+        )?;
+        writeln!(output)
+    }
+
+    fn print_analysis<W: Write>(&mut self, output: &mut W) -> io::Result<()> {
+        let analysis = match analyze_with(&self.state, self.depth, None, &mut *self.search) {
+            Ok(analysis) => analysis,
+            Err(error) => return print_error(error, output),
+        };
+
+        writeln!(output, "Minimax:")?;
+
+        for (i, entry) in analysis.iter().enumerate() {
+            let line: Vec<String> = entry.line.iter().map(Notation::format).collect();
+
+            writeln!(
+                output,
+                "{}. {}: {:?} ({})",
+                i + 1,
+                entry.mov.format(),
+                entry.outcome,
+                line.join(" | ")
+            )?;
+        }
+
+        writeln!(output)
+    }
+
+    fn print_review<W: Write>(&mut self, output: &mut W) -> io::Result<()> {
+        let initial = match self.history.first() {
+            Some((state, _mov)) => state.clone(),
+            None => self.state.clone(),
+        };
+
+        let moves = self.get_moves();
+
+        let reviews = match review_game_with(&initial, &moves, self.depth, &mut *self.search) {
+            Ok(reviews) => reviews,
+            Err(error) => return print_error(error, output),
+        };
+
+        writeln!(output, "Review:")?;
+
+        for (i, review) in reviews.iter().enumerate() {
+            let class = match review.get_class() {
+                OutcomeClass::Won => "won",
+                OutcomeClass::Drawn => "drawn",
+                OutcomeClass::Lost => "lost",
+                OutcomeClass::Undecided => "undecided",
+            };
+
+            if review.is_blunder() {
+                let best: Vec<String> = review.best_moves.iter().map(Notation::format).collect();
+
+                writeln!(
+                    output,
+                    "{}. {:?} {}?? {} (best: {}, {:?})",
+                    i + 1,
+                    S::get_side(review.player),
+                    review.mov.format(),
+                    class,
+                    best.join(" | "),
+                    review.best_outcome
+                )?;
+            } else {
+                writeln!(
+                    output,
+                    "{}. {:?} {} {}",
+                    i + 1,
+                    S::get_side(review.player),
+                    review.mov.format(),
+                    class
+                )?;
+            }
+        }
+
+        writeln!(output)
+    }
+
+    fn print_board<W: Write>(&self, output: &mut W) -> io::Result<()> {
+        writeln!(output, "{}", self.state)?;
+
+        match self.state.get_status() {
+            Running(player) => writeln!(output, "{:?} to move.", S::get_side(player))?,
+            Finished(Win(player)) => {
+                writeln!(output, "Game over: {:?} wins.", S::get_side(player))?
+            }
+            Finished(Draw) => writeln!(output, "Game over: draw.")?,
+        }
+
+        writeln!(output)
+    }
+
+    fn print_help<W: Write>(&self, output: &mut W) -> io::Result<()> {
+        writeln!(output, "Enter a move or one of the following commands:")?;
+        writeln!(output)?;
+        writeln!(output, "hint: Show the best moves.")?;
+        writeln!(output, "undo: Take back the last move.")?;
+        writeln!(output, "analyze: Rank every possible move.")?;
+        writeln!(output, "play [depth]: Let the computer make a move.")?;
+        writeln!(output, "board: Show the board.")?;
+        writeln!(output, "review: Review the moves played so far.")?;
+        writeln!(output, "help: Show this message.")?;
+        writeln!(output, "quit: Leave the game.")?;
+        writeln!(output)
+    }
+}
+
+fn print_error<S, W>(error: MinimaxError<S>, output: &mut W) -> io::Result<()>
+where
+    S: GameState,
+    S::Move: Notation,
+    S::MoveError: Debug,
+    W: Write,
+{
+    match error {
+        MinimaxError::GameAlreadyFinished => writeln!(output, "The game is already finished.")?,
+        MinimaxError::MoveError(error) => writeln!(output, "Move error: {:?}", error)?,
+        MinimaxError::NoPossibleMoves => writeln!(output, "There are no possible moves.")?,
+        MinimaxError::UnlistedMove(mov) => writeln!(
+            output,
+            "The move {} is not listed as possible.",
+            mov.format()
+        )?,
+        MinimaxError::Aborted => writeln!(output, "The search was aborted.")?,
+    }
+
+    writeln!(output)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::TakeAway;
+
+    #[test]
+    fn test_repl() {
+        assert_eq!(Command::parse("2"), Ok(Command::Move(2_u8)));
+        assert_eq!(Command::<u8>::parse(" HINT "), Ok(Command::Hint));
+        assert_eq!(Command::<u8>::parse("play"), Ok(Command::Play(None)));
+        assert_eq!(Command::<u8>::parse("play 3"), Ok(Command::Play(Some(3))));
+        assert_eq!(Command::<u8>::parse("play x"), Err(InvalidNotation));
+        assert_eq!(Command::<u8>::parse("undo 2"), Err(InvalidNotation));
+        assert_eq!(Command::<u8>::parse(""), Err(InvalidNotation));
+
+        let mut repl = Repl::new(TakeAway::new(5), 5);
+
+        let input = "3\n1\nfoo\nundo\n2\nhint\nplay\nreview\nquit\n1\n";
+        let mut output = Vec::new();
+
+        repl.run(input.as_bytes(), &mut output).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("Cannot make that move: InvalidTake(3)"));
+        assert!(output.contains("Invalid input: foo"));
+        assert!(output.contains("Hint: 1 | 2 (Definite(Win(One), 1))"));
+        assert!(output.contains("Playing 1: Definite(Win(One), 1)"));
+        assert!(output.contains("1. One 2 won\n2. Two 1 lost\n"));
+        assert!(output.contains("2 tokens\nOne to move.\n"));
+
+        assert_eq!(repl.get_moves(), vec![2, 1]);
+        assert_eq!(repl.get_state().tokens, 2);
+
+        repl.execute(Command::Undo, &mut io::sink()).unwrap();
+        assert_eq!(
+            repl.get_state(),
+            &TakeAway {
+                tokens: 3,
+                player: Player::Two,
+            }
+        );
+    }
+}
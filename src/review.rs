@@ -0,0 +1,116 @@
+use crate::GameResult::*;
+use crate::Outcome::*;
+use crate::{
+    compare_outcome, evaluate_moves, GameState, MinimaxError, MinimaxResult, Outcome, Player,
+};
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum OutcomeClass {
+    Won,
+    Drawn,
+    Lost,
+    Undecided,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct MoveReview<S: GameState> {
+    pub player: Player,
+    pub mov: S::Move,
+    pub outcome: Outcome,
+    pub best_outcome: Outcome,
+    pub best_moves: Vec<S::Move>,
+}
+
+pub type ReviewResult<S> = Result<Vec<MoveReview<S>>, MinimaxError<S>>;
+
+pub fn classify_outcome(player: Player, outcome: &Outcome) -> OutcomeClass {
+    match outcome {
+        Definite(Win(winner), _) if *winner == player => OutcomeClass::Won,
+        Definite(Win(_), _) => OutcomeClass::Lost,
+        Definite(Draw, _) => OutcomeClass::Drawn,
+        Indefinite(_) => OutcomeClass::Undecided,
+    }
+}
+
+impl<S: GameState> MoveReview<S> {
+    pub fn get_class(&self) -> OutcomeClass {
+        classify_outcome(self.player, &self.outcome)
+    }
+
+    pub fn get_best_class(&self) -> OutcomeClass {
+        classify_outcome(self.player, &self.best_outcome)
+    }
+
+    pub fn is_best(&self) -> bool {
+        self.outcome == self.best_outcome
+    }
+
+    pub fn is_blunder(&self) -> bool {
+        self.get_class() != self.get_best_class()
+    }
+}
+
+pub fn review_game<S>(initial: &S, moves: &[S::Move], depth: usize) -> ReviewResult<S>
+where
+    S: GameState,
+    S::Move: Clone,
+{
+    review_game_with(initial, moves, depth, &mut |child_state, depth| {
+        child_state.minimax(depth)
+    })
+}
+
+pub fn review_game_with<S>(
+    initial: &S,
+    moves: &[S::Move],
+    depth: usize,
+    child_minimax: &mut dyn FnMut(&S, usize) -> MinimaxResult<S>,
+) -> ReviewResult<S>
+where
+    S: GameState,
+    S::Move: Clone,
+{
+    let mut state = initial.clone();
+    let mut reviews = Vec::new();
+
+    for mov in moves.iter() {
+        let mut next_state = state.clone();
+        next_state.make_move(mov).map_err(MinimaxError::MoveError)?;
+
+        let (player, outcomes) = evaluate_moves(&state, depth, child_minimax, &|child_state| {
+            Indefinite(child_state.get_score())
+        })?;
+
+        let outcome = match outcomes
+            .iter()
+            .find(|(possible_move, _outcome)| possible_move == mov)
+        {
+            Some((_mov, outcome)) => *outcome,
+            None => return Err(MinimaxError::UnlistedMove(mov.clone())),
+        };
+
+        let best_outcome = outcomes
+            .iter()
+            .map(|(_mov, outcome)| *outcome)
+            .max_by(|lhs, rhs| compare_outcome(player, lhs, rhs))
+            .ok_or(MinimaxError::NoPossibleMoves)?;
+
+        let best_moves = outcomes
+            .into_iter()
+            .filter(|(_mov, outcome)| *outcome == best_outcome)
+            .map(|(mov, _outcome)| mov)
+            .collect();
+
+        reviews.push(MoveReview {
+            player,
+            mov: mov.clone(),
+            outcome,
+            best_outcome,
+            best_moves,
+        });
+
+        state = next_state;
+    }
+
+    Ok(reviews)
+}
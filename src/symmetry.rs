@@ -0,0 +1,81 @@
+use crate::GameState;
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum SquareSymmetry {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    FlipHorizontal,
+    FlipVertical,
+    FlipDiagonal,
+    FlipAntiDiagonal,
+}
+
+pub trait Symmetric: GameState {
+    type Symmetry: Copy;
+
+    fn symmetries() -> Vec<Self::Symmetry>;
+    fn inverse(symmetry: Self::Symmetry) -> Self::Symmetry;
+    fn transform(&self, symmetry: Self::Symmetry) -> Self;
+    fn transform_move(mov: &Self::Move, symmetry: Self::Symmetry) -> Self::Move;
+    fn canonical_form(&self) -> (Self, Self::Symmetry);
+}
+
+impl SquareSymmetry {
+    pub fn values() -> impl Iterator<Item = SquareSymmetry> {
+        [
+            SquareSymmetry::Identity,
+            SquareSymmetry::Rotate90,
+            SquareSymmetry::Rotate180,
+            SquareSymmetry::Rotate270,
+            SquareSymmetry::FlipHorizontal,
+            SquareSymmetry::FlipVertical,
+            SquareSymmetry::FlipDiagonal,
+            SquareSymmetry::FlipAntiDiagonal,
+        ]
+        .iter()
+        .cloned()
+    }
+
+    pub fn inverse(self) -> SquareSymmetry {
+        match self {
+            SquareSymmetry::Rotate90 => SquareSymmetry::Rotate270,
+            SquareSymmetry::Rotate270 => SquareSymmetry::Rotate90,
+            _ => self,
+        }
+    }
+
+    pub fn apply_vector(self, dx: isize, dy: isize) -> (isize, isize) {
+        match self {
+            SquareSymmetry::Identity => (dx, dy),
+            SquareSymmetry::Rotate90 => (-dy, dx),
+            SquareSymmetry::Rotate180 => (-dx, -dy),
+            SquareSymmetry::Rotate270 => (dy, -dx),
+            SquareSymmetry::FlipHorizontal => (-dx, dy),
+            SquareSymmetry::FlipVertical => (dx, -dy),
+            SquareSymmetry::FlipDiagonal => (dy, dx),
+            SquareSymmetry::FlipAntiDiagonal => (-dy, -dx),
+        }
+    }
+
+    pub fn apply(self, x: usize, y: usize, size: usize) -> (usize, usize) {
+        let max = size as isize - 1;
+        let (dx, dy) = self.apply_vector(2 * x as isize - max, 2 * y as isize - max);
+
+        (((dx + max) / 2) as usize, ((dy + max) / 2) as usize)
+    }
+}
+
+pub fn canonical_form_by_key<S, K, F>(state: &S, key: F) -> (S, S::Symmetry)
+where
+    S: Symmetric,
+    K: Ord,
+    F: Fn(&S) -> K,
+{
+    S::symmetries()
+        .into_iter()
+        .map(|symmetry| (state.transform(symmetry), symmetry))
+        .min_by_key(|(transformed, _symmetry)| key(transformed))
+        .expect("No symmetries.")
+}
@@ -0,0 +1,371 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+use std::convert::TryFrom;
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use crate::codec::{invalid_data, Codec};
+use crate::GameResult::*;
+use crate::Outcome::*;
+use crate::{compare_outcome, search, GameResult, GameState, MinimaxError, MinimaxResult, Player};
+use crate::{Outcome, Status};
+
+const MAGIC: &[u8; 4] = b"MMTB";
+const MAX_MOVES: u32 = u32::MAX >> 2;
+
+struct Node {
+    player: Player,
+    remaining: usize,
+    best: Option<Outcome>,
+    result: Option<(GameResult, usize)>,
+}
+
+pub struct Tablebase<S: GameState> {
+    entries: HashMap<S, (GameResult, usize)>,
+}
+
+impl Node {
+    fn new(player: Player, remaining: usize) -> Node {
+        Node {
+            player,
+            remaining,
+            best: None,
+            result: None,
+        }
+    }
+
+    fn update(&mut self, outcome: Outcome) -> Option<(GameResult, usize)> {
+        if self.result.is_some() {
+            return None;
+        }
+
+        self.remaining -= 1;
+
+        self.best = match self.best {
+            Some(best) if compare_outcome(self.player, &best, &outcome) != Ordering::Less => {
+                Some(best)
+            }
+            _ => Some(outcome),
+        };
+
+        self.result = match (outcome, self.best) {
+            (Definite(Win(winner), moves), _) if winner == self.player => {
+                Some((Win(winner), moves))
+            }
+            (_, Some(Definite(result, moves))) if self.remaining == 0 => Some((result, moves)),
+            _ => None,
+        };
+
+        self.result
+    }
+}
+
+fn encode_result(result: GameResult, moves: usize) -> io::Result<u32> {
+    let code = match result {
+        Draw => 0,
+        Win(Player::One) => 1,
+        Win(Player::Two) => 2,
+    };
+
+    match u32::try_from(moves) {
+        Ok(moves) if moves <= MAX_MOVES => Ok(moves << 2 | code),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "The distance is too large for a tablebase entry.",
+        )),
+    }
+}
+
+fn decode_result(value: u32) -> io::Result<(GameResult, usize)> {
+    let result = match value & 0b11 {
+        0 => Draw,
+        1 => Win(Player::One),
+        2 => Win(Player::Two),
+        _ => return Err(invalid_data("Invalid tablebase result.")),
+    };
+
+    Ok((result, (value >> 2) as usize))
+}
+
+impl<S> Tablebase<S>
+where
+    S: GameState + Eq + Hash,
+{
+    pub fn build(root: &S) -> Result<Tablebase<S>, MinimaxError<S>> {
+        let mut index: HashMap<S, usize> = HashMap::new();
+        let mut states = Vec::new();
+        let mut predecessors: Vec<Vec<usize>> = Vec::new();
+        let mut nodes = Vec::new();
+        let mut finished = Vec::new();
+
+        if let Status::Running(_) = root.get_status() {
+            index.insert(root.clone(), 0);
+            states.push(root.clone());
+            predecessors.push(Vec::new());
+        }
+
+        while let Some(state) = states.get(nodes.len()).cloned() {
+            let player = match state.get_status() {
+                Status::Running(player) => player,
+                Status::Finished(_) => return Err(MinimaxError::GameAlreadyFinished),
+            };
+
+            let moves = state.possible_moves();
+
+            if moves.is_empty() {
+                return Err(MinimaxError::NoPossibleMoves);
+            }
+
+            let parent = nodes.len();
+            nodes.push(Node::new(player, moves.len()));
+
+            for mov in moves.iter() {
+                let mut child = state.clone();
+                child.make_move(mov).map_err(MinimaxError::MoveError)?;
+
+                if let Status::Finished(result) = child.get_status() {
+                    finished.push((parent, result));
+                    continue;
+                }
+
+                let child_index = match index.get(&child) {
+                    Some(&child_index) => child_index,
+                    None => {
+                        index.insert(child.clone(), states.len());
+                        states.push(child);
+                        predecessors.push(Vec::new());
+                        states.len() - 1
+                    }
+                };
+
+                predecessors[child_index].push(parent);
+            }
+        }
+
+        let mut queue = BinaryHeap::new();
+
+        for (parent, result) in finished {
+            if let Some((_result, moves)) = nodes[parent].update(Definite(result, 0)) {
+                queue.push(Reverse((moves + 1, parent)));
+            }
+        }
+
+        while let Some(Reverse((moves, child))) = queue.pop() {
+            let result = match nodes[child].result {
+                Some((result, _moves)) => result,
+                None => continue,
+            };
+
+            for &parent in predecessors[child].iter() {
+                if let Some((_result, moves)) = nodes[parent].update(Definite(result, moves)) {
+                    queue.push(Reverse((moves + 1, parent)));
+                }
+            }
+        }
+
+        let entries = states
+            .into_iter()
+            .zip(nodes)
+            .map(|(state, node)| (state, node.result.unwrap_or((Draw, 0))))
+            .collect();
+
+        Ok(Tablebase { entries })
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn probe(&self, state: &S) -> Option<Outcome> {
+        self.entries
+            .get(state)
+            .map(|&(result, moves)| Definite(result, moves))
+    }
+
+    pub fn minimax(&self, state: &S, depth: usize) -> MinimaxResult<S> {
+        search(
+            state,
+            depth,
+            &mut |child_state, depth| self.minimax(child_state, depth),
+            &|child_state| match self.probe(child_state) {
+                Some(Definite(result, moves)) => Definite(result, moves + 1),
+                _ => Indefinite(child_state.get_score()),
+            },
+        )
+    }
+}
+
+impl<S> Tablebase<S>
+where
+    S: GameState + Eq + Hash + Codec,
+{
+    pub fn save<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        (self.entries.len() as u64).encode(writer)?;
+
+        for (state, &(result, moves)) in self.entries.iter() {
+            state.encode(writer)?;
+            encode_result(result, moves)?.encode(writer)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn load<R: Read>(reader: &mut R) -> io::Result<Tablebase<S>> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+
+        if &magic != MAGIC {
+            return Err(invalid_data("Not a tablebase file."));
+        }
+
+        let len = u64::decode(reader)?;
+        let mut entries = HashMap::new();
+
+        for _ in 0..len {
+            let state = S::decode(reader)?;
+            let result = decode_result(u32::decode(reader)?)?;
+            entries.insert(state, result);
+        }
+
+        Ok(Tablebase { entries })
+    }
+
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.save(&mut writer)?;
+        writer.flush()
+    }
+
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> io::Result<Tablebase<S>> {
+        Tablebase::load(&mut BufReader::new(File::open(path)?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::TakeAway;
+
+    #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+    struct CyclicGame(usize);
+
+    const CYCLIC_GAME: [(Status, &[usize]); 9] = [
+        (Status::Running(Player::One), &[1, 2]),
+        (Status::Running(Player::Two), &[0, 3]),
+        (Status::Running(Player::Two), &[4]),
+        (Status::Finished(Win(Player::One)), &[]),
+        (Status::Finished(Win(Player::One)), &[]),
+        (Status::Running(Player::One), &[6, 7]),
+        (Status::Running(Player::Two), &[5, 8]),
+        (Status::Finished(Win(Player::Two)), &[]),
+        (Status::Finished(Win(Player::One)), &[]),
+    ];
+
+    impl GameState for CyclicGame {
+        type Move = usize;
+        type MoveError = ();
+
+        fn get_status(&self) -> Status {
+            CYCLIC_GAME[self.0].0
+        }
+
+        fn possible_moves(&self) -> Vec<usize> {
+            CYCLIC_GAME[self.0].1.to_vec()
+        }
+
+        fn make_move(&mut self, position: &usize) -> Result<(), ()> {
+            if !CYCLIC_GAME[self.0].1.contains(position) {
+                return Err(());
+            }
+
+            self.0 = *position;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_tablebase() {
+        let tablebase = Tablebase::build(&TakeAway::new(5)).unwrap();
+        let losing = TakeAway {
+            tokens: 3,
+            player: Player::One,
+        };
+
+        assert_eq!(tablebase.len(), 8);
+        assert_eq!(
+            tablebase.probe(&TakeAway::new(5)),
+            Some(Definite(Win(Player::One), 2))
+        );
+        assert_eq!(
+            tablebase.probe(&losing),
+            Some(Definite(Win(Player::Two), 1))
+        );
+        assert_eq!(tablebase.probe(&TakeAway::new(6)), None);
+        assert_eq!(
+            tablebase
+                .minimax(&TakeAway::new(5), 0)
+                .map(|minimax| minimax.moves),
+            Ok(vec![2])
+        );
+
+        let mut bytes = Vec::new();
+        assert!(tablebase.save(&mut bytes).is_ok());
+
+        let loaded = Tablebase::<TakeAway>::load(&mut bytes.as_slice()).unwrap();
+
+        assert_eq!(loaded.len(), tablebase.len());
+        assert_eq!(loaded.probe(&losing), tablebase.probe(&losing));
+        assert!(Tablebase::<TakeAway>::load(&mut &bytes[1..]).is_err());
+    }
+
+    #[test]
+    fn test_cyclic_tablebase() {
+        let tablebase = Tablebase::build(&CyclicGame(0)).unwrap();
+        let win = |moves| Some(Definite(Win(Player::One), moves));
+
+        assert_eq!(tablebase.len(), 3);
+        assert_eq!(tablebase.probe(&CyclicGame(0)), win(1));
+        assert_eq!(tablebase.probe(&CyclicGame(1)), win(2));
+        assert_eq!(tablebase.probe(&CyclicGame(2)), win(0));
+
+        let tablebase = Tablebase::build(&CyclicGame(5)).unwrap();
+        let draw = Some(Definite(Draw, 0));
+
+        assert_eq!(tablebase.len(), 2);
+        assert_eq!(tablebase.probe(&CyclicGame(5)), draw);
+        assert_eq!(tablebase.probe(&CyclicGame(6)), draw);
+
+        assert_eq!(
+            tablebase
+                .minimax(&CyclicGame(5), 0)
+                .map(|minimax| minimax.moves),
+            Ok(vec![6])
+        );
+    }
+
+    #[test]
+    fn test_encode_result() {
+        let max_moves = MAX_MOVES as usize;
+
+        for &(result, moves) in [
+            (Draw, 0),
+            (Win(Player::One), 7),
+            (Win(Player::Two), max_moves),
+        ]
+        .iter()
+        {
+            let value = encode_result(result, moves).unwrap();
+            assert_eq!(decode_result(value).unwrap(), (result, moves));
+        }
+
+        assert!(encode_result(Draw, max_moves + 1).is_err());
+        assert!(decode_result(3).is_err());
+    }
+}
@@ -0,0 +1,145 @@
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Read, Write};
+
+use crate::codec::{invalid_data, Codec};
+use crate::notation::{InvalidNotation, Notation};
+use crate::repl::Sides;
+use crate::{GameResult, GameState, Player, Status};
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct TakeAway {
+    pub tokens: u8,
+    pub player: Player,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct InvalidTake(pub u8);
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct UncheckedTakeAway(pub TakeAway);
+
+impl TakeAway {
+    pub fn new(tokens: u8) -> TakeAway {
+        TakeAway {
+            tokens,
+            player: Player::One,
+        }
+    }
+}
+
+impl GameState for TakeAway {
+    type Move = u8;
+    type MoveError = InvalidTake;
+
+    fn get_status(&self) -> Status {
+        match self.tokens {
+            0 => Status::Finished(GameResult::Win(self.player.other())),
+            _ => Status::Running(self.player),
+        }
+    }
+
+    fn possible_moves(&self) -> Vec<u8> {
+        (1..=self.tokens.min(2)).collect()
+    }
+
+    fn make_move(&mut self, take: &u8) -> Result<(), InvalidTake> {
+        if *take == 0 || *take > 2 || *take > self.tokens {
+            return Err(InvalidTake(*take));
+        }
+
+        self.tokens -= take;
+        self.player = self.player.other();
+        Ok(())
+    }
+}
+
+impl GameState for UncheckedTakeAway {
+    type Move = u8;
+    type MoveError = InvalidTake;
+
+    fn get_status(&self) -> Status {
+        self.0.get_status()
+    }
+
+    fn possible_moves(&self) -> Vec<u8> {
+        match self.0.tokens {
+            0 => Vec::new(),
+            _ => vec![1, 2],
+        }
+    }
+
+    fn make_move(&mut self, take: &u8) -> Result<(), InvalidTake> {
+        self.0.make_move(take)
+    }
+}
+
+impl Codec for TakeAway {
+    fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.tokens.encode(writer)?;
+
+        match self.player {
+            Player::One => 0_u8.encode(writer),
+            Player::Two => 1_u8.encode(writer),
+        }
+    }
+
+    fn decode<R: Read>(reader: &mut R) -> io::Result<TakeAway> {
+        let tokens = u8::decode(reader)?;
+
+        let player = match u8::decode(reader)? {
+            0 => Player::One,
+            1 => Player::Two,
+            _ => return Err(invalid_data("Invalid take away player.")),
+        };
+
+        Ok(TakeAway { tokens, player })
+    }
+}
+
+impl Display for TakeAway {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} tokens", self.tokens)
+    }
+}
+
+impl Sides for TakeAway {
+    type Side = Player;
+
+    fn get_side(player: Player) -> Player {
+        player
+    }
+}
+
+impl Notation for u8 {
+    fn format(&self) -> String {
+        self.to_string()
+    }
+
+    fn parse(input: &str) -> Result<u8, InvalidNotation> {
+        input.trim().parse().map_err(|_| InvalidNotation)
+    }
+}
+
+impl Notation for TakeAway {
+    fn format(&self) -> String {
+        match self.player {
+            Player::One => format!("{}:1", self.tokens),
+            Player::Two => format!("{}:2", self.tokens),
+        }
+    }
+
+    fn parse(input: &str) -> Result<TakeAway, InvalidNotation> {
+        let (tokens, player) = input.trim().split_once(':').ok_or(InvalidNotation)?;
+
+        let player = match player {
+            "1" => Player::One,
+            "2" => Player::Two,
+            _ => return Err(InvalidNotation),
+        };
+
+        Ok(TakeAway {
+            tokens: u8::parse(tokens)?,
+            player,
+        })
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::symmetry::Symmetric;
+use crate::{minimax_with, GameState, Minimax, MinimaxResult};
+
+pub struct TranspositionTable<S: GameState> {
+    entries: HashMap<(S, usize), Minimax<S>>,
+    hits: usize,
+}
+
+impl<S> TranspositionTable<S>
+where
+    S: GameState + Eq + Hash,
+    S::Move: Clone,
+{
+    pub fn new() -> TranspositionTable<S> {
+        TranspositionTable {
+            entries: HashMap::new(),
+            hits: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get_hits(&self) -> usize {
+        self.hits
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.hits = 0;
+    }
+
+    fn get_or_search(
+        &mut self,
+        state: &S,
+        depth: usize,
+        child_minimax: fn(&mut Self, &S, usize) -> MinimaxResult<S>,
+    ) -> MinimaxResult<S> {
+        let key = (state.clone(), depth);
+
+        if let Some(minimax) = self.entries.get(&key) {
+            self.hits += 1;
+            return Ok(minimax.clone());
+        }
+
+        let minimax = minimax_with(state, depth, &mut |child_state, depth| {
+            child_minimax(self, child_state, depth)
+        })?;
+
+        self.entries.insert(key, minimax.clone());
+        Ok(minimax)
+    }
+
+    pub fn minimax(&mut self, state: &S, depth: usize) -> MinimaxResult<S> {
+        self.get_or_search(state, depth, Self::minimax)
+    }
+
+    pub fn minimax_symmetric(&mut self, state: &S, depth: usize) -> MinimaxResult<S>
+    where
+        S: Symmetric,
+    {
+        let (canonical_state, symmetry) = state.canonical_form();
+        let inverse = S::inverse(symmetry);
+
+        let minimax = self.get_or_search(&canonical_state, depth, Self::minimax_symmetric)?;
+
+        Ok(Minimax {
+            outcome: minimax.outcome,
+            moves: minimax
+                .moves
+                .iter()
+                .map(|mov| S::transform_move(mov, inverse))
+                .collect(),
+        })
+    }
+}
+
+impl<S> Default for TranspositionTable<S>
+where
+    S: GameState + Eq + Hash,
+    S::Move: Clone,
+{
+    fn default() -> TranspositionTable<S> {
+        TranspositionTable::new()
+    }
+}
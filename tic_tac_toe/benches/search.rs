@@ -0,0 +1,30 @@
+use ::minimax::benchmark::BenchmarkSuite;
+
+use tic_tac_toe::*;
+
+const POSITIONS: [(&str, &str); 3] = [
+    ("empty", ".........:X"),
+    ("opening", "X...O....:X"),
+    ("middlegame", "XO..X...O:X"),
+];
+
+fn main() {
+    let mut suite = BenchmarkSuite::from_args();
+
+    for (name, position) in POSITIONS.iter() {
+        let game: TicTacToe = position.parse().expect("Invalid benchmark position.");
+        let moves = game.possible_moves().len();
+
+        for depth in [2, 4].iter() {
+            suite.bench_minimax(&format!("minimax/{}/depth {}", name, depth), &game, *depth);
+        }
+
+        suite.bench_minimax(&format!("minimax/{}/solve", name), &game, moves - 1);
+
+        suite.bench_perft(&format!("perft/{}/depth 1", name), &game, 1);
+        suite.bench_perft(&format!("perft/{}/depth 3", name), &game, 3);
+        suite.bench_perft(&format!("perft/{}/solve", name), &game, moves);
+    }
+
+    suite.finish();
+}
@@ -0,0 +1,13 @@
+use ::minimax::engine::Engine;
+
+use std::io;
+
+use tic_tac_toe::*;
+
+fn main() {
+    let mut engine = Engine::new(TicTacToe::new(), GRID_SIZE);
+
+    engine
+        .run(io::stdin().lock(), io::stdout())
+        .expect("It was not possible to communicate through the standard streams.");
+}
@@ -37,3 +37,55 @@ impl fmt::Display for TicTacToe {
         Ok(())
     }
 }
+
+impl fmt::Display for MnkGame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.get_height() {
+            if y > 0 {
+                let separators = vec!["---"; self.get_width()];
+                writeln!(f, "{}", separators.join("+"))?;
+            }
+
+            let cells = (0..self.get_width())
+                .map(|x| format!(" {} ", get_char(self.get_cell(Cell::new(x, y)))))
+                .collect::<Vec<_>>();
+
+            writeln!(f, "{}", cells.join("|"))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for UltimateTicTacToe {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..GRID_SIZE {
+            if y > 0 && y % GRID_WIDTH == 0 {
+                writeln!(f, "-------+-------+-------")?;
+            }
+
+            let boards = (0..GRID_WIDTH)
+                .map(|board_x| {
+                    let board = TicTacToe::get_grid_place(y / GRID_WIDTH * GRID_WIDTH + board_x);
+
+                    (0..GRID_WIDTH)
+                        .map(|x| {
+                            let place = TicTacToe::get_grid_place(y % GRID_WIDTH * GRID_WIDTH + x);
+
+                            match self.get_place(board, place) {
+                                None => '.',
+                                player => get_char(player),
+                            }
+                        })
+                        .map(|c| c.to_string())
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .collect::<Vec<_>>();
+
+            writeln!(f, " {} ", boards.join(" | "))?;
+        }
+
+        Ok(())
+    }
+}
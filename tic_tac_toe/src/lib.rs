@@ -1,12 +1,32 @@
 use std::ops::Index;
+use std::str::FromStr;
+
+pub use crate::mnk::{Cell, MnkGame, MnkMoveError};
+pub use crate::solver::SolvedTable;
+pub use crate::ultimate::{UltimateMove, UltimateMoveError, UltimateTicTacToe};
 
 mod display;
 mod minimax;
+mod mnk;
+mod solver;
+mod ultimate;
 
 #[cfg(test)]
 mod test;
 
-const GRID_SIZE: usize = 9;
+const GRID_WIDTH: usize = 3;
+pub const GRID_SIZE: usize = GRID_WIDTH * GRID_WIDTH;
+
+const TRIPLES: [[Place; 3]; 8] = [
+    [Place::UpperLeft, Place::Upper, Place::UpperRight],
+    [Place::Left, Place::Center, Place::Right],
+    [Place::LowerLeft, Place::Lower, Place::LowerRight],
+    [Place::UpperLeft, Place::Left, Place::LowerLeft],
+    [Place::Upper, Place::Center, Place::Lower],
+    [Place::UpperRight, Place::Right, Place::LowerRight],
+    [Place::UpperLeft, Place::Center, Place::LowerRight],
+    [Place::UpperRight, Place::Center, Place::LowerLeft],
+];
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
 pub enum Place {
@@ -52,6 +72,18 @@ pub enum MoveError {
     PlaceAlreadyUsed(Place, Player),
 }
 
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ParseError {
+    MissingSide,
+    InvalidSide,
+    InvalidLength(usize),
+    InvalidCharacter(char),
+    InvalidCount(usize, usize),
+    BothPlayersWon,
+    InvalidWinner(Player),
+    WrongSide(Status),
+}
+
 impl Player {
     fn other(self) -> Player {
         match self {
@@ -97,6 +129,42 @@ impl TicTacToe {
         self.status
     }
 
+    pub(crate) fn from_grid(grid: [Option<Player>; GRID_SIZE]) -> Result<TicTacToe, ParseError> {
+        let mut game = TicTacToe::new();
+        game.grid = grid;
+
+        let count = |player| game.grid.iter().filter(|&&p| p == Some(player)).count();
+        let (x_count, o_count) = (count(Player::X), count(Player::O));
+
+        let next_player = match x_count.checked_sub(o_count) {
+            Some(0) => Player::X,
+            Some(1) => Player::O,
+            _ => return Err(ParseError::InvalidCount(x_count, o_count)),
+        };
+
+        let winners: Vec<Player> = [Player::X, Player::O]
+            .iter()
+            .cloned()
+            .filter(|&player| {
+                TRIPLES
+                    .iter()
+                    .any(|&triple| check_triple(&game.grid, triple) == Some(player))
+            })
+            .collect();
+
+        game.status = match winners.as_slice() {
+            [] => match check_grid(&game.grid) {
+                Some(result) => Status::Finished(result),
+                None => Status::Running(next_player),
+            },
+            &[winner] if winner == next_player => return Err(ParseError::InvalidWinner(winner)),
+            &[winner] => Status::Finished(GameResult::Win(winner)),
+            _ => return Err(ParseError::BothPlayersWon),
+        };
+
+        Ok(game)
+    }
+
     fn get_grid_index(place: Place) -> usize {
         match place {
             Place::UpperLeft => 0,
@@ -150,37 +218,8 @@ impl TicTacToe {
         }
     }
 
-    fn check_triple(&self, places: [Place; 3]) -> Option<Player> {
-        let player = self[places[0]];
-
-        if places.iter().map(|&p| self[p]).all(|p| p == player) {
-            player
-        } else {
-            None
-        }
-    }
-
     fn check_win(&self) -> Option<GameResult> {
-        let triples = [
-            [Place::UpperLeft, Place::Upper, Place::UpperRight],
-            [Place::Left, Place::Center, Place::Right],
-            [Place::LowerLeft, Place::Lower, Place::LowerRight],
-            [Place::UpperLeft, Place::Left, Place::LowerLeft],
-            [Place::Upper, Place::Center, Place::Lower],
-            [Place::UpperRight, Place::Right, Place::LowerRight],
-            [Place::UpperLeft, Place::Center, Place::LowerRight],
-            [Place::UpperRight, Place::Center, Place::LowerLeft],
-        ];
-
-        let winner = triples.iter().filter_map(|&t| self.check_triple(t)).next();
-
-        let all_filled = self.grid.iter().all(|p| p.is_some());
-
-        if winner.is_some() || all_filled {
-            Some(GameResult::from(winner))
-        } else {
-            None
-        }
+        check_grid(&self.grid)
     }
 
     pub fn make_move(&mut self, player: Player, place: Place) -> Result<(), MoveError> {
@@ -205,6 +244,99 @@ impl TicTacToe {
             }
         }
     }
+
+    pub fn to_compact_string(&self) -> String {
+        let grid: String = self
+            .grid
+            .iter()
+            .map(|place| match place {
+                Some(Player::X) => 'X',
+                Some(Player::O) => 'O',
+                None => '.',
+            })
+            .collect();
+
+        let side = match self.status {
+            Status::Running(Player::X) => "X",
+            Status::Running(Player::O) => "O",
+            Status::Finished(_) => "-",
+        };
+
+        format!("{}:{}", grid, side)
+    }
+}
+
+fn check_triple(grid: &[Option<Player>; GRID_SIZE], places: [Place; 3]) -> Option<Player> {
+    let player = grid[TicTacToe::get_grid_index(places[0])];
+
+    if places
+        .iter()
+        .map(|&p| grid[TicTacToe::get_grid_index(p)])
+        .all(|p| p == player)
+    {
+        player
+    } else {
+        None
+    }
+}
+
+fn get_winner(grid: &[Option<Player>; GRID_SIZE]) -> Option<Player> {
+    TRIPLES.iter().filter_map(|&t| check_triple(grid, t)).next()
+}
+
+fn check_grid(grid: &[Option<Player>; GRID_SIZE]) -> Option<GameResult> {
+    let winner = get_winner(grid);
+
+    let all_filled = grid.iter().all(|p| p.is_some());
+
+    if winner.is_some() || all_filled {
+        Some(GameResult::from(winner))
+    } else {
+        None
+    }
+}
+
+impl FromStr for TicTacToe {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<TicTacToe, ParseError> {
+        let (grid, side) = input
+            .trim()
+            .split_once(':')
+            .ok_or(ParseError::MissingSide)?;
+
+        let side = match side {
+            "X" | "x" => Some(Player::X),
+            "O" | "o" => Some(Player::O),
+            "-" => None,
+            _ => return Err(ParseError::InvalidSide),
+        };
+
+        let length = grid.chars().count();
+
+        if length != GRID_SIZE {
+            return Err(ParseError::InvalidLength(length));
+        }
+
+        let mut places = [None; GRID_SIZE];
+
+        for (place, c) in places.iter_mut().zip(grid.chars()) {
+            *place = match c.to_ascii_uppercase() {
+                'X' => Some(Player::X),
+                'O' => Some(Player::O),
+                '.' => None,
+                c => return Err(ParseError::InvalidCharacter(c)),
+            };
+        }
+
+        let game = TicTacToe::from_grid(places)?;
+
+        match (game.status, side) {
+            (Status::Running(player), Some(side)) if player == side => Ok(game),
+            (Status::Finished(_), None) => Ok(game),
+            (status, _) => Err(ParseError::WrongSide(status)),
+        }
+    }
 }
 
 impl Default for TicTacToe {
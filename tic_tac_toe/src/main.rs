@@ -1,25 +1,10 @@
-use ::minimax::{GameState, MinimaxResult};
+use ::minimax::repl::Repl;
+use ::minimax::GameState;
 
 use std::io;
 
 use tic_tac_toe::*;
 
-use tic_tac_toe::GameResult::*;
-use tic_tac_toe::MoveError::*;
-use tic_tac_toe::Place::*;
-use tic_tac_toe::Status::*;
-
-enum TicTacToeError {
-    MoveError(MoveError),
-    InvalidInput(String),
-}
-
-impl From<MoveError> for TicTacToeError {
-    fn from(error: MoveError) -> TicTacToeError {
-        TicTacToeError::MoveError(error)
-    }
-}
-
 fn print_instructions() {
     println!("-----------------------------");
     println!("         TIC TAC TOE         ");
@@ -35,127 +20,17 @@ fn print_instructions() {
     println!();
 }
 
-fn print_minimax(game: &TicTacToe) -> MinimaxResult<TicTacToe> {
-    const MINIMAX_DEPTH: usize = 8;
-
-    print!("Minimax: ");
-
-    let minimax = match game.minimax(MINIMAX_DEPTH) {
-        Ok(minimax) => {
-            println!("{:?}", minimax.outcome);
-            minimax
-        }
-        Err(error) => {
-            println!("{:?}\n", error);
-            return Err(error);
-        }
-    };
-
-    for (i, place) in minimax.moves.iter().enumerate() {
-        print!(
-            "{}",
-            match place {
-                UpperLeft => "Q",
-                Upper => "W",
-                UpperRight => "E",
-                Left => "A",
-                Center => "S",
-                Right => "D",
-                LowerLeft => "Z",
-                Lower => "X",
-                LowerRight => "C",
-            }
-        );
-
-        if i < minimax.moves.len() {
-            print!(", ");
-        }
-    }
-
-    println!("\n");
-    Ok(minimax)
-}
-
-fn parse_input(input: String) -> Result<Place, TicTacToeError> {
-    match input.as_str() {
-        "Q" => Ok(UpperLeft),
-        "W" => Ok(Upper),
-        "E" => Ok(UpperRight),
-        "A" => Ok(Left),
-        "S" => Ok(Center),
-        "D" => Ok(Right),
-        "Z" => Ok(LowerLeft),
-        "X" => Ok(Lower),
-        "C" => Ok(LowerRight),
-        _ => Err(TicTacToeError::InvalidInput(input)),
-    }
-}
-
-fn handle_turn(game: &mut TicTacToe, player: Player) -> Result<(), TicTacToeError> {
-    let input = {
-        let mut buffer = String::new();
-
-        io::stdin()
-            .read_line(&mut buffer)
-            .expect("It was not possible to read user input.");
-
-        println!();
-
-        buffer.trim().to_uppercase()
-    };
-
-    let place = parse_input(input)?;
-
-    game.make_move(player, place)?;
-    Ok(())
-}
-
-fn handle_error(error: TicTacToeError) {
-    match error {
-        TicTacToeError::MoveError(InvalidStatus(_)) => {
-            panic!("Cannot make any move now because the status of the game doesn't allow it.");
-        }
-        TicTacToeError::MoveError(WrongPlayer(_)) => {
-            panic!("Cannot make that move because it's not the player's turn.");
-        }
-        TicTacToeError::MoveError(PlaceAlreadyUsed(_, _)) => {
-            println!("Cannot make that move because that place is already used.");
-        }
-        TicTacToeError::InvalidInput(input) => {
-            println!("Invalid input: {}", input);
-            println!("Please, enter one of the following: Q, W, E, A, S, D, Z, X, or C.");
-        }
-    }
-
-    println!();
-}
-
 fn main() {
     print_instructions();
 
-    let mut game = TicTacToe::new();
-
-    loop {
-        match game.get_status() {
-            Running(player) => {
-                print_minimax(&game).ok();
-
-                let result = handle_turn(&mut game, player);
-
-                if let Err(error) = result {
-                    handle_error(error);
-                }
-
-                println!("{}", game);
-            }
-            Finished(result) => {
-                match result {
-                    Draw => println!("Draw."),
-                    Win(player) => println!("{} wins.", player),
-                }
-
-                break;
-            }
+    let mut repl = Repl::with_search(TicTacToe::new(), GRID_SIZE, |game, depth| {
+        if depth >= game.possible_moves().len() {
+            SolvedTable::global().minimax(game)
+        } else {
+            GameState::minimax(game, depth)
         }
-    }
+    });
+
+    repl.run(io::stdin().lock(), &mut io::stdout())
+        .expect("It was not possible to read user input.");
 }
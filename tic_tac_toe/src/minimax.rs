@@ -1,5 +1,11 @@
 use crate::*;
 
+use std::io::{self, Read, Write};
+
+use ::minimax::codec::{invalid_data, Codec};
+use ::minimax::notation::{InvalidNotation, Notation};
+use ::minimax::repl::Sides;
+use ::minimax::symmetry::{canonical_form_by_key, SquareSymmetry, Symmetric};
 use ::minimax::{
     GameResult as MinimaxGameResult, GameState, Player as MinimaxPlayer, Status as MinimaxStatus,
 };
@@ -77,3 +83,189 @@ impl GameState for TicTacToe {
         }
     }
 }
+
+fn get_grid_code(game: &TicTacToe) -> u16 {
+    game.grid.iter().fold(0, |code, player| {
+        3 * code
+            + match player {
+                None => 0,
+                Some(Player::X) => 1,
+                Some(Player::O) => 2,
+            }
+    })
+}
+
+fn transform_index(index: usize, symmetry: SquareSymmetry) -> usize {
+    let (x, y) = symmetry.apply(index % GRID_WIDTH, index / GRID_WIDTH, GRID_WIDTH);
+    y * GRID_WIDTH + x
+}
+
+impl Sides for TicTacToe {
+    type Side = Player;
+
+    fn get_side(player: MinimaxPlayer) -> Player {
+        player.into()
+    }
+}
+
+impl Symmetric for TicTacToe {
+    type Symmetry = SquareSymmetry;
+
+    fn symmetries() -> Vec<SquareSymmetry> {
+        SquareSymmetry::values().collect()
+    }
+
+    fn inverse(symmetry: SquareSymmetry) -> SquareSymmetry {
+        symmetry.inverse()
+    }
+
+    fn transform(&self, symmetry: SquareSymmetry) -> TicTacToe {
+        let mut game = self.clone();
+
+        for (i, &player) in self.grid.iter().enumerate() {
+            game.grid[transform_index(i, symmetry)] = player;
+        }
+
+        game
+    }
+
+    fn transform_move(place: &Place, symmetry: SquareSymmetry) -> Place {
+        TicTacToe::get_grid_place(transform_index(TicTacToe::get_grid_index(*place), symmetry))
+    }
+
+    fn canonical_form(&self) -> (TicTacToe, SquareSymmetry) {
+        canonical_form_by_key(self, get_grid_code)
+    }
+}
+
+impl GameState for MnkGame {
+    type Move = Cell;
+    type MoveError = MnkMoveError;
+
+    fn get_status(&self) -> MinimaxStatus {
+        self.get_status().into()
+    }
+
+    fn possible_moves(&self) -> Vec<Cell> {
+        self.possible_moves()
+    }
+
+    fn make_move(&mut self, mov: &Cell) -> Result<(), MnkMoveError> {
+        match self.get_status() {
+            Status::Finished(_) => Err(MnkMoveError::InvalidStatus(self.get_status())),
+            Status::Running(player) => self.make_move(player, *mov),
+        }
+    }
+
+    fn get_score(&self) -> isize {
+        self.get_threat_score()
+    }
+}
+
+impl GameState for UltimateTicTacToe {
+    type Move = UltimateMove;
+    type MoveError = UltimateMoveError;
+
+    fn get_status(&self) -> MinimaxStatus {
+        self.get_status().into()
+    }
+
+    fn possible_moves(&self) -> Vec<UltimateMove> {
+        self.possible_moves()
+    }
+
+    fn make_move(&mut self, mov: &UltimateMove) -> Result<(), UltimateMoveError> {
+        match self.get_status() {
+            Status::Finished(_) => Err(UltimateMoveError::InvalidStatus(self.get_status())),
+            Status::Running(player) => self.make_move(player, *mov),
+        }
+    }
+
+    fn get_score(&self) -> isize {
+        self.get_board_score()
+    }
+}
+
+impl Codec for TicTacToe {
+    fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        get_grid_code(self).encode(writer)
+    }
+
+    fn decode<R: Read>(reader: &mut R) -> io::Result<TicTacToe> {
+        let mut code = u16::decode(reader)?;
+        let mut grid = [None; GRID_SIZE];
+
+        for place in grid.iter_mut().rev() {
+            *place = match code % 3 {
+                0 => None,
+                1 => Some(Player::X),
+                _ => Some(Player::O),
+            };
+
+            code /= 3;
+        }
+
+        if code > 0 {
+            return Err(invalid_data("Invalid tic tac toe grid code."));
+        }
+
+        TicTacToe::from_grid(grid)
+            .map_err(|error| invalid_data(&format!("Invalid tic tac toe grid: {:?}", error)))
+    }
+}
+
+impl Codec for Place {
+    fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        (TicTacToe::get_grid_index(*self) as u8).encode(writer)
+    }
+
+    fn decode<R: Read>(reader: &mut R) -> io::Result<Place> {
+        match u8::decode(reader)? as usize {
+            index if index < GRID_SIZE => Ok(TicTacToe::get_grid_place(index)),
+            _ => Err(invalid_data("Invalid tic tac toe place.")),
+        }
+    }
+}
+
+impl Notation for Place {
+    fn format(&self) -> String {
+        let key = match self {
+            Place::UpperLeft => "Q",
+            Place::Upper => "W",
+            Place::UpperRight => "E",
+            Place::Left => "A",
+            Place::Center => "S",
+            Place::Right => "D",
+            Place::LowerLeft => "Z",
+            Place::Lower => "X",
+            Place::LowerRight => "C",
+        };
+
+        key.to_string()
+    }
+
+    fn parse(input: &str) -> Result<Place, InvalidNotation> {
+        match input.trim().to_uppercase().as_str() {
+            "Q" => Ok(Place::UpperLeft),
+            "W" => Ok(Place::Upper),
+            "E" => Ok(Place::UpperRight),
+            "A" => Ok(Place::Left),
+            "S" => Ok(Place::Center),
+            "D" => Ok(Place::Right),
+            "Z" => Ok(Place::LowerLeft),
+            "X" => Ok(Place::Lower),
+            "C" => Ok(Place::LowerRight),
+            _ => Err(InvalidNotation),
+        }
+    }
+}
+
+impl Notation for TicTacToe {
+    fn format(&self) -> String {
+        self.to_compact_string()
+    }
+
+    fn parse(input: &str) -> Result<TicTacToe, InvalidNotation> {
+        input.parse().map_err(|_| InvalidNotation)
+    }
+}
@@ -0,0 +1,231 @@
+use crate::*;
+
+const DIRECTIONS: [(isize, isize); 4] = [(1, 0), (0, 1), (1, 1), (1, -1)];
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Cell {
+    pub x: usize,
+    pub y: usize,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct MnkGame {
+    width: usize,
+    height: usize,
+    k: usize,
+    status: Status,
+    grid: Vec<Option<Player>>,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum MnkMoveError {
+    InvalidStatus(Status),
+    WrongPlayer(Player),
+    CellOutOfBounds(Cell),
+    CellAlreadyUsed(Cell, Player),
+}
+
+impl Cell {
+    pub fn new(x: usize, y: usize) -> Cell {
+        Cell { x, y }
+    }
+}
+
+impl From<Place> for Cell {
+    fn from(place: Place) -> Cell {
+        let index = TicTacToe::get_grid_index(place);
+        Cell::new(index % GRID_WIDTH, index / GRID_WIDTH)
+    }
+}
+
+impl MnkGame {
+    pub fn new(width: usize, height: usize, k: usize) -> MnkGame {
+        if width == 0 || height == 0 || k == 0 {
+            panic!(
+                "{}x{} with {} in a row is not a valid m,n,k game.",
+                width, height, k
+            );
+        }
+
+        MnkGame {
+            width,
+            height,
+            k,
+            status: Status::Running(Player::X),
+            grid: vec![None; width * height],
+        }
+    }
+
+    pub fn tic_tac_toe() -> MnkGame {
+        MnkGame::new(3, 3, 3)
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get_k(&self) -> usize {
+        self.k
+    }
+
+    pub fn get_status(&self) -> Status {
+        self.status
+    }
+
+    fn get_grid_index(&self, cell: Cell) -> Option<usize> {
+        if cell.x < self.width && cell.y < self.height {
+            Some(cell.y * self.width + cell.x)
+        } else {
+            None
+        }
+    }
+
+    fn get_grid_cell(&self, index: usize) -> Cell {
+        Cell::new(index % self.width, index / self.width)
+    }
+
+    pub fn get_cell(&self, cell: Cell) -> Option<Player> {
+        self.get_grid_index(cell).and_then(|i| self.grid[i])
+    }
+
+    fn get_offset_cell(&self, cell: Cell, dx: isize, dy: isize) -> Option<Cell> {
+        let x = cell.x as isize + dx;
+        let y = cell.y as isize + dy;
+
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            None
+        } else {
+            Some(Cell::new(x as usize, y as usize))
+        }
+    }
+
+    pub fn possible_moves(&self) -> Vec<Cell> {
+        match self.status {
+            Status::Running(_) => self
+                .grid
+                .iter()
+                .enumerate()
+                .filter(|(_, p)| p.is_none())
+                .map(|(i, _)| self.get_grid_cell(i))
+                .collect(),
+            Status::Finished(_) => Vec::new(),
+        }
+    }
+
+    fn count_direction(&self, cell: Cell, player: Player, dx: isize, dy: isize) -> usize {
+        let mut count = 0;
+        let mut cursor = self.get_offset_cell(cell, dx, dy);
+
+        while let Some(c) = cursor {
+            if self.get_cell(c) != Some(player) {
+                break;
+            }
+
+            count += 1;
+            cursor = self.get_offset_cell(c, dx, dy);
+        }
+
+        count
+    }
+
+    fn check_win(&self, cell: Cell, player: Player) -> Option<GameResult> {
+        let won = DIRECTIONS.iter().any(|&(dx, dy)| {
+            let line = 1
+                + self.count_direction(cell, player, dx, dy)
+                + self.count_direction(cell, player, -dx, -dy);
+
+            line >= self.k
+        });
+
+        let all_filled = self.grid.iter().all(|p| p.is_some());
+
+        if won {
+            Some(GameResult::Win(player))
+        } else if all_filled {
+            Some(GameResult::Draw)
+        } else {
+            None
+        }
+    }
+
+    pub fn make_move(&mut self, player: Player, cell: Cell) -> Result<(), MnkMoveError> {
+        match self.status {
+            Status::Finished(_) => Err(MnkMoveError::InvalidStatus(self.status)),
+            Status::Running(status_player) => {
+                let index = self
+                    .get_grid_index(cell)
+                    .ok_or(MnkMoveError::CellOutOfBounds(cell))?;
+
+                if let Some(cell_player) = self.grid[index] {
+                    return Err(MnkMoveError::CellAlreadyUsed(cell, cell_player));
+                } else if status_player != player {
+                    return Err(MnkMoveError::WrongPlayer(status_player));
+                }
+
+                self.grid[index] = Some(player);
+
+                self.status = if let Some(result) = self.check_win(cell, player) {
+                    Status::Finished(result)
+                } else {
+                    Status::Running(player.other())
+                };
+
+                Ok(())
+            }
+        }
+    }
+
+    fn get_window_owner(&self, cell: Cell, dx: isize, dy: isize) -> Option<(Player, usize)> {
+        let mut owner = None;
+        let mut count = 0;
+        let mut cursor = Some(cell);
+
+        for _ in 0..self.k {
+            let c = cursor?;
+
+            if let Some(player) = self.get_cell(c) {
+                match owner {
+                    Some(owner) if owner != player => return None,
+                    _ => owner = Some(player),
+                }
+
+                count += 1;
+            }
+
+            cursor = self.get_offset_cell(c, dx, dy);
+        }
+
+        owner.map(|player| (player, count))
+    }
+
+    pub fn get_threat_score(&self) -> isize {
+        let mut score = 0;
+
+        for index in 0..self.grid.len() {
+            let cell = self.get_grid_cell(index);
+
+            for &(dx, dy) in DIRECTIONS.iter() {
+                if let Some((player, count)) = self.get_window_owner(cell, dx, dy) {
+                    let threat = (count * count) as isize;
+
+                    score += match player {
+                        Player::X => threat,
+                        Player::O => -threat,
+                    };
+                }
+            }
+        }
+
+        score
+    }
+}
+
+impl Default for MnkGame {
+    fn default() -> MnkGame {
+        MnkGame::tic_tac_toe()
+    }
+}
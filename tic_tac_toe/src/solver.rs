@@ -0,0 +1,90 @@
+use crate::*;
+
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
+use ::minimax::{minimax_with, GameState, Minimax, MinimaxError, MinimaxResult};
+
+pub struct SolvedTable {
+    positions: HashMap<TicTacToe, Minimax<TicTacToe>>,
+}
+
+impl SolvedTable {
+    pub fn new() -> SolvedTable {
+        let mut plies = vec![vec![TicTacToe::new()]];
+
+        while let Some(games) = plies.last() {
+            let next_games: HashSet<TicTacToe> = games
+                .iter()
+                .flat_map(|game| {
+                    game.possible_moves().into_iter().map(move |place| {
+                        let mut child = game.clone();
+                        GameState::make_move(&mut child, &place).expect("Invalid move.");
+                        child
+                    })
+                })
+                .collect();
+
+            if next_games.is_empty() {
+                break;
+            }
+
+            plies.push(next_games.into_iter().collect());
+        }
+
+        let mut positions = HashMap::new();
+
+        for game in plies.into_iter().rev().flatten() {
+            if let Some(minimax) = SolvedTable::solve_position(&positions, &game) {
+                positions.insert(game, minimax);
+            }
+        }
+
+        SolvedTable { positions }
+    }
+
+    pub fn global() -> &'static SolvedTable {
+        static TABLE: OnceLock<SolvedTable> = OnceLock::new();
+        TABLE.get_or_init(SolvedTable::new)
+    }
+
+    fn solve_position(
+        positions: &HashMap<TicTacToe, Minimax<TicTacToe>>,
+        game: &TicTacToe,
+    ) -> Option<Minimax<TicTacToe>> {
+        minimax_with(game, GRID_SIZE, &mut |child, _depth| {
+            Ok(positions[child].clone())
+        })
+        .ok()
+    }
+
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (&TicTacToe, &Minimax<TicTacToe>)> {
+        self.positions.iter()
+    }
+
+    pub fn get(&self, game: &TicTacToe) -> Option<&Minimax<TicTacToe>> {
+        self.positions.get(game)
+    }
+
+    pub fn minimax(&self, game: &TicTacToe) -> MinimaxResult<TicTacToe> {
+        match (game.get_status(), self.get(game)) {
+            (Status::Finished(_), _) => Err(MinimaxError::GameAlreadyFinished),
+            (Status::Running(_), Some(minimax)) => Ok(minimax.clone()),
+            (Status::Running(_), None) => GameState::minimax(game, GRID_SIZE),
+        }
+    }
+}
+
+impl Default for SolvedTable {
+    fn default() -> SolvedTable {
+        SolvedTable::new()
+    }
+}
@@ -1,3 +1,21 @@
+use ::minimax::analysis::{analyze, analyze_with};
+use ::minimax::codec::Codec;
+use ::minimax::conformance::{check_conformance, Violation};
+use ::minimax::notation::{InvalidNotation, Notation};
+use ::minimax::perft::{divide, perft, PerftCount};
+use ::minimax::review::{review_game, review_game_with, OutcomeClass};
+use ::minimax::symmetry::Symmetric;
+use ::minimax::tablebase::Tablebase;
+use ::minimax::transposition::TranspositionTable;
+use ::minimax::Outcome::*;
+use ::minimax::{
+    compare_outcome, GameResult as MinimaxGameResult, GameState, MinimaxError,
+    Status as MinimaxStatus,
+};
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
 use crate::GameResult::*;
 use crate::MoveError::*;
 use crate::Status::*;
@@ -31,3 +49,688 @@ fn test_tic_tac_toe() {
         Err(InvalidStatus(Finished(Win(Player::X))))
     );
 }
+
+fn assert_same_game_tree(game: &TicTacToe, mnk: &MnkGame) {
+    assert_eq!(game.get_status(), mnk.get_status());
+
+    let moves = game.possible_moves();
+    let cells: Vec<Cell> = moves.iter().map(|&p| Cell::from(p)).collect();
+
+    assert_eq!(cells, mnk.possible_moves());
+
+    for (&place, &cell) in moves.iter().zip(cells.iter()) {
+        let player = match game.get_status() {
+            Running(player) => player,
+            Finished(_) => unreachable!(),
+        };
+
+        let mut game = game.clone();
+        let mut mnk = mnk.clone();
+
+        assert_eq!(game.make_move(player, place), Ok(()));
+        assert_eq!(mnk.make_move(player, cell), Ok(()));
+        assert_same_game_tree(&game, &mnk);
+    }
+}
+
+#[test]
+fn test_mnk_tic_tac_toe() {
+    assert_same_game_tree(&TicTacToe::new(), &MnkGame::tic_tac_toe());
+
+    let mut game = TicTacToe::new();
+    let mut mnk = MnkGame::tic_tac_toe();
+
+    for &place in [Place::Center, Place::UpperLeft].iter() {
+        let player = match game.get_status() {
+            Running(player) => player,
+            Finished(_) => unreachable!(),
+        };
+
+        assert_eq!(game.make_move(player, place), Ok(()));
+        assert_eq!(mnk.make_move(player, Cell::from(place)), Ok(()));
+    }
+
+    let game_minimax = game.minimax(8).unwrap();
+    let mnk_minimax = mnk.minimax(8).unwrap();
+
+    assert_eq!(game_minimax.outcome, mnk_minimax.outcome);
+    assert_eq!(
+        game_minimax
+            .moves
+            .into_iter()
+            .map(Cell::from)
+            .collect::<Vec<_>>(),
+        mnk_minimax.moves
+    );
+}
+
+#[test]
+fn test_mnk_game() {
+    let mut game = MnkGame::new(4, 4, 4);
+
+    assert_eq!(
+        game.make_move(Player::X, Cell::new(4, 0)),
+        Err(MnkMoveError::CellOutOfBounds(Cell::new(4, 0)))
+    );
+
+    for i in 0..3 {
+        assert_eq!(game.make_move(Player::X, Cell::new(i, i)), Ok(()));
+        assert_eq!(game.make_move(Player::O, Cell::new(i, 3 - i)), Ok(()));
+    }
+
+    assert_eq!(
+        game.make_move(Player::X, Cell::new(0, 3)),
+        Err(MnkMoveError::CellAlreadyUsed(Cell::new(0, 3), Player::O))
+    );
+
+    assert_eq!(game.get_status(), Running(Player::X));
+    assert_eq!(game.make_move(Player::X, Cell::new(3, 3)), Ok(()));
+    assert_eq!(game.get_status(), Finished(Win(Player::X)));
+
+    let mut game = MnkGame::new(15, 15, 5);
+
+    for x in [5, 7, 8, 9].iter().cloned() {
+        assert_eq!(game.make_move(Player::X, Cell::new(x, 7)), Ok(()));
+        assert_eq!(game.make_move(Player::O, Cell::new(x, 8)), Ok(()));
+    }
+
+    assert_eq!(game.get_threat_score(), 0);
+    assert_eq!(game.make_move(Player::X, Cell::new(6, 7)), Ok(()));
+    assert_eq!(game.get_status(), Finished(Win(Player::X)));
+    assert!(game.get_threat_score() > 0);
+}
+
+#[test]
+fn test_symmetric_minimax() {
+    let mut game = TicTacToe::new();
+
+    assert_eq!(game.make_move(Player::X, Place::Upper), Ok(()));
+
+    let minimax = game.minimax(7).unwrap();
+    let moves: HashSet<Place> = minimax.moves.iter().cloned().collect();
+
+    for symmetry in TicTacToe::symmetries() {
+        let transformed = game.transform(symmetry).minimax(7).unwrap();
+
+        assert_eq!(transformed.outcome, minimax.outcome);
+        assert_eq!(
+            transformed.moves.into_iter().collect::<HashSet<_>>(),
+            moves
+                .iter()
+                .map(|place| TicTacToe::transform_move(place, symmetry))
+                .collect()
+        );
+    }
+
+    let mut table = TranspositionTable::new();
+    let mut symmetric_table = TranspositionTable::new();
+
+    let cached = table.minimax(&game, 7).unwrap();
+    let symmetric = symmetric_table.minimax_symmetric(&game, 7).unwrap();
+
+    assert_eq!(cached.outcome, minimax.outcome);
+    assert_eq!(symmetric.outcome, minimax.outcome);
+    assert_eq!(cached.moves.into_iter().collect::<HashSet<_>>(), moves);
+    assert_eq!(symmetric.moves.into_iter().collect::<HashSet<_>>(), moves);
+
+    assert!(symmetric_table.get_hits() > 0);
+    assert!(symmetric_table.len() < table.len());
+}
+
+#[test]
+fn test_solved_table() {
+    let table = SolvedTable::new();
+
+    assert_eq!(table.len(), 4520);
+    assert_eq!(
+        table.get(&TicTacToe::new()).unwrap().outcome,
+        Definite(MinimaxGameResult::Draw, 8)
+    );
+
+    for (game, minimax) in table.positions() {
+        assert_eq!(game.minimax(8).as_ref(), Ok(minimax));
+    }
+
+    let mut game = TicTacToe::new();
+
+    assert_eq!(game.make_move(Player::X, Place::Center), Ok(()));
+    assert_eq!(game.make_move(Player::O, Place::Upper), Ok(()));
+
+    assert_eq!(
+        table.minimax(&game).unwrap().outcome,
+        Definite(MinimaxGameResult::Win(Player::X.into()), 4)
+    );
+
+    assert_eq!(game.make_move(Player::X, Place::UpperLeft), Ok(()));
+    assert_eq!(game.make_move(Player::O, Place::LowerRight), Ok(()));
+    assert_eq!(game.make_move(Player::X, Place::Left), Ok(()));
+    assert_eq!(game.make_move(Player::O, Place::Right), Ok(()));
+    assert_eq!(game.make_move(Player::X, Place::LowerLeft), Ok(()));
+
+    assert_eq!(table.get(&game), None);
+    assert_eq!(table.minimax(&game), Err(MinimaxError::GameAlreadyFinished));
+}
+
+#[test]
+fn test_tablebase() {
+    let solved_table = SolvedTable::global();
+    let tablebase = Tablebase::build(&TicTacToe::new()).unwrap();
+
+    assert_eq!(tablebase.len(), solved_table.len());
+
+    for (game, minimax) in solved_table.positions() {
+        assert_eq!(tablebase.probe(game), Some(minimax.outcome));
+        assert_eq!(tablebase.minimax(game, 0).as_ref(), Ok(minimax));
+    }
+
+    let mut bytes = Vec::new();
+    assert!(tablebase.save(&mut bytes).is_ok());
+    assert_eq!(bytes.len(), 4 + 8 + tablebase.len() * (2 + 4));
+
+    let loaded = Tablebase::<TicTacToe>::load(&mut bytes.as_slice()).unwrap();
+
+    for (game, minimax) in solved_table.positions() {
+        assert_eq!(loaded.probe(game), Some(minimax.outcome));
+
+        let mut bytes = Vec::new();
+        assert!(game.encode(&mut bytes).is_ok());
+        assert_eq!(
+            TicTacToe::decode(&mut bytes.as_slice()).ok().as_ref(),
+            Some(game)
+        );
+    }
+
+    assert!(Tablebase::<TicTacToe>::load(&mut &bytes[1..]).is_err());
+
+    for code in [10179_u16, 10143, 3_u16.pow(9)].iter() {
+        let mut bytes = Vec::new();
+        assert!(code.encode(&mut bytes).is_ok());
+        assert!(TicTacToe::decode(&mut bytes.as_slice()).is_err());
+    }
+}
+
+#[test]
+fn test_ultimate() {
+    let mut game = UltimateTicTacToe::new();
+
+    assert_eq!(game.possible_moves().len(), GRID_SIZE * GRID_SIZE);
+
+    let mov = UltimateMove::new(Place::Center, Place::UpperLeft);
+    assert_eq!(game.make_move(Player::X, mov), Ok(()));
+    assert_eq!(game.get_next_board(), Some(Place::UpperLeft));
+    assert_eq!(
+        game.get_place(Place::Center, Place::UpperLeft),
+        Some(Player::X)
+    );
+
+    let moves = game.possible_moves();
+    assert_eq!(moves.len(), GRID_SIZE);
+    assert!(moves.iter().all(|m| m.board == Place::UpperLeft));
+
+    assert_eq!(
+        game.make_move(Player::O, UltimateMove::new(Place::Center, Place::Upper)),
+        Err(UltimateMoveError::WrongBoard(Place::UpperLeft))
+    );
+
+    assert_eq!(
+        game.make_move(
+            Player::X,
+            UltimateMove::new(Place::UpperLeft, Place::Center)
+        ),
+        Err(UltimateMoveError::WrongPlayer(Player::O))
+    );
+
+    assert_eq!(
+        game.make_move(
+            Player::O,
+            UltimateMove::new(Place::UpperLeft, Place::Center)
+        ),
+        Ok(())
+    );
+
+    assert_eq!(
+        game.make_move(Player::X, mov),
+        Err(UltimateMoveError::PlaceAlreadyUsed(mov, Player::X))
+    );
+
+    assert_eq!(game.get_status(), Running(Player::X));
+}
+
+#[test]
+fn test_ultimate_finished_boards() {
+    let moves = [
+        (3, 7),
+        (7, 1),
+        (1, 4),
+        (4, 1),
+        (1, 6),
+        (6, 0),
+        (0, 0),
+        (0, 1),
+        (1, 3),
+        (3, 2),
+        (2, 4),
+        (4, 2),
+        (2, 7),
+        (7, 0),
+        (0, 8),
+        (8, 0),
+        (0, 4),
+        (4, 4),
+        (4, 3),
+        (3, 4),
+        (4, 0),
+        (7, 2),
+        (2, 1),
+        (1, 1),
+        (1, 0),
+    ];
+
+    let moves: Vec<UltimateMove> = moves
+        .iter()
+        .map(|&(board, place)| {
+            UltimateMove::new(
+                TicTacToe::get_grid_place(board),
+                TicTacToe::get_grid_place(place),
+            )
+        })
+        .collect();
+
+    let mut game = UltimateTicTacToe::new();
+
+    for &mov in moves[..21].iter() {
+        assert_eq!(GameState::make_move(&mut game, &mov), Ok(()));
+    }
+
+    assert_eq!(
+        game.get_board_result(Place::UpperLeft),
+        Some(Win(Player::X))
+    );
+    assert_eq!(game.get_next_board(), None);
+    assert!(game
+        .possible_moves()
+        .iter()
+        .all(|m| m.board != Place::UpperLeft));
+
+    assert_eq!(
+        game.make_move(Player::O, UltimateMove::new(Place::UpperLeft, Place::Upper)),
+        Err(UltimateMoveError::BoardAlreadyFinished(
+            Place::UpperLeft,
+            Win(Player::X)
+        ))
+    );
+
+    for &mov in moves[21..24].iter() {
+        assert_eq!(GameState::make_move(&mut game, &mov), Ok(()));
+    }
+
+    let minimax = game.minimax(1).unwrap();
+    assert_eq!(
+        minimax.outcome,
+        Definite(MinimaxGameResult::Win(Player::X.into()), 0)
+    );
+    assert!(minimax.moves.contains(&moves[24]));
+
+    assert_eq!(game.make_move(Player::X, moves[24]), Ok(()));
+    assert_eq!(game.get_board_result(Place::Upper), Some(Win(Player::X)));
+    assert_eq!(game.get_status(), Finished(Win(Player::X)));
+    assert!(game.possible_moves().is_empty());
+}
+
+#[test]
+fn test_analysis() {
+    let mut game = TicTacToe::new();
+
+    assert_eq!(game.make_move(Player::X, Place::Center), Ok(()));
+    assert_eq!(game.make_move(Player::O, Place::Upper), Ok(()));
+
+    let minimax = game.minimax(7).unwrap();
+    let analysis = analyze(&game, 7, None).unwrap();
+
+    assert_eq!(analysis.len(), 7);
+    assert_eq!(analysis[0].outcome, minimax.outcome);
+
+    let best_moves: Vec<Place> = analysis
+        .iter()
+        .filter(|entry| entry.outcome == minimax.outcome)
+        .map(|entry| entry.mov)
+        .collect();
+
+    assert_eq!(best_moves.len(), minimax.moves.len());
+    assert!(best_moves.iter().all(|place| minimax.moves.contains(place)));
+
+    for pair in analysis.windows(2) {
+        assert_ne!(
+            compare_outcome(::minimax::Player::One, &pair[0].outcome, &pair[1].outcome),
+            Ordering::Less
+        );
+    }
+
+    for entry in analysis.iter() {
+        assert_eq!(entry.line[0], entry.mov);
+
+        let mut line_game = game.clone();
+
+        for place in entry.line.iter() {
+            assert_eq!(GameState::make_move(&mut line_game, place), Ok(()));
+        }
+
+        match entry.outcome {
+            Definite(result, moves) => {
+                assert_eq!(entry.line.len(), moves + 1);
+                assert_eq!(line_game.get_status(), Finished(result.into()));
+            }
+            Indefinite(_) => panic!("Unexpected outcome {:?}.", entry.outcome),
+        }
+    }
+
+    let top = analyze(&game, 7, Some(2)).unwrap();
+    assert_eq!(top, analysis[..2].to_vec());
+
+    let mut searches = 0;
+
+    let best = analyze_with(&game, 7, Some(1), &mut |child, depth| {
+        searches += 1;
+        child.minimax(depth)
+    })
+    .unwrap();
+
+    assert_eq!(best, analysis[..1].to_vec());
+    assert_eq!(searches, analysis.len() + best[0].line.len() - 2);
+
+    let solved = analyze_with(&game, 7, None, &mut |child, _depth| {
+        SolvedTable::global().minimax(child)
+    })
+    .unwrap();
+
+    assert_eq!(
+        solved.iter().map(|entry| entry.outcome).collect::<Vec<_>>(),
+        analysis
+            .iter()
+            .map(|entry| entry.outcome)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_review() {
+    let game = TicTacToe::new();
+
+    let moves = [
+        Place::Left,
+        Place::Center,
+        Place::UpperLeft,
+        Place::Upper,
+        Place::Lower,
+        Place::LowerLeft,
+    ];
+
+    let reviews = review_game(&game, &moves, 9).unwrap();
+
+    assert_eq!(reviews.len(), moves.len());
+
+    let classes: Vec<(OutcomeClass, OutcomeClass)> = reviews
+        .iter()
+        .map(|review| (review.get_class(), review.get_best_class()))
+        .collect();
+
+    assert_eq!(
+        classes,
+        vec![
+            (OutcomeClass::Drawn, OutcomeClass::Drawn),
+            (OutcomeClass::Drawn, OutcomeClass::Drawn),
+            (OutcomeClass::Drawn, OutcomeClass::Drawn),
+            (OutcomeClass::Lost, OutcomeClass::Drawn),
+            (OutcomeClass::Drawn, OutcomeClass::Won),
+            (OutcomeClass::Drawn, OutcomeClass::Drawn),
+        ]
+    );
+
+    let blunders: Vec<usize> = reviews
+        .iter()
+        .enumerate()
+        .filter(|(_i, review)| review.is_blunder())
+        .map(|(i, _review)| i)
+        .collect();
+
+    assert_eq!(blunders, vec![3, 4]);
+
+    assert_eq!(reviews[3].player, ::minimax::Player::Two);
+    assert_eq!(reviews[3].best_moves, vec![Place::LowerLeft]);
+    assert_eq!(reviews[4].player, ::minimax::Player::One);
+    assert_eq!(reviews[4].best_moves, vec![Place::LowerLeft]);
+    assert_eq!(
+        reviews[4].best_outcome,
+        Definite(MinimaxGameResult::Win(::minimax::Player::One), 0)
+    );
+
+    for review in reviews.iter().filter(|review| !review.is_blunder()) {
+        assert_eq!(review.get_class(), review.get_best_class());
+    }
+
+    let solved = review_game_with(&game, &moves, 9, &mut |child, _depth| {
+        SolvedTable::global().minimax(child)
+    })
+    .unwrap();
+
+    assert_eq!(solved, reviews);
+
+    let illegal = [Place::Center, Place::Center];
+
+    assert_eq!(
+        review_game(&game, &illegal, 9),
+        Err(MinimaxError::MoveError(PlaceAlreadyUsed(
+            Place::Center,
+            Player::X
+        )))
+    );
+
+    assert_eq!(
+        review_game(&CenterOnlyTicTacToe(game), &[Place::Left], 9),
+        Err(MinimaxError::UnlistedMove(Place::Left))
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct CenterOnlyTicTacToe(TicTacToe);
+
+impl GameState for CenterOnlyTicTacToe {
+    type Move = Place;
+    type MoveError = MoveError;
+
+    fn get_status(&self) -> MinimaxStatus {
+        GameState::get_status(&self.0)
+    }
+
+    fn possible_moves(&self) -> Vec<Place> {
+        match self.0.get_place(Place::Center) {
+            None => vec![Place::Center],
+            Some(_) => self.0.possible_moves(),
+        }
+    }
+
+    fn make_move(&mut self, place: &Place) -> Result<(), MoveError> {
+        GameState::make_move(&mut self.0, place)
+    }
+}
+
+#[test]
+fn test_notation() {
+    let places = TicTacToe::new().possible_moves();
+
+    assert_eq!(places.len(), 9);
+
+    for place in places.iter() {
+        assert_eq!(Place::parse(&place.format()), Ok(*place));
+    }
+
+    let keys: HashSet<String> = places.iter().map(Notation::format).collect();
+    assert_eq!(keys.len(), 9);
+
+    assert_eq!(Place::UpperLeft.format(), "Q");
+    assert_eq!(Place::LowerRight.format(), "C");
+    assert_eq!(Place::parse("s"), Ok(Place::Center));
+    assert_eq!(Place::parse(" W \n"), Ok(Place::Upper));
+    assert_eq!(Place::parse(""), Err(InvalidNotation));
+    assert_eq!(Place::parse("QW"), Err(InvalidNotation));
+    assert_eq!(Place::parse("F"), Err(InvalidNotation));
+}
+
+#[test]
+fn test_state_notation() {
+    let mut game = TicTacToe::new();
+
+    assert_eq!(game.format(), ".........:X");
+    assert_eq!(TicTacToe::parse(".........:X"), Ok(game.clone()));
+
+    assert_eq!(game.make_move(Player::X, Place::Center), Ok(()));
+    assert_eq!(game.make_move(Player::O, Place::UpperRight), Ok(()));
+
+    assert_eq!(game.format(), "..O.X....:X");
+    assert_eq!(TicTacToe::parse("..o.x....:x"), Ok(game.clone()));
+
+    assert_eq!(game.make_move(Player::X, Place::UpperLeft), Ok(()));
+    assert_eq!(game.make_move(Player::O, Place::Upper), Ok(()));
+    assert_eq!(game.make_move(Player::X, Place::LowerRight), Ok(()));
+
+    assert_eq!(game.get_status(), Finished(Win(Player::X)));
+    assert_eq!(TicTacToe::parse(&game.format()), Ok(game));
+
+    assert_eq!(TicTacToe::parse("........:X"), Err(InvalidNotation));
+    assert_eq!(TicTacToe::parse("..........:X"), Err(InvalidNotation));
+    assert_eq!(TicTacToe::parse("....-....:X"), Err(InvalidNotation));
+    assert_eq!(TicTacToe::parse("XX.......:O"), Err(InvalidNotation));
+    assert_eq!(TicTacToe::parse("O........:X"), Err(InvalidNotation));
+    assert_eq!(TicTacToe::parse("XXXOO.O..:-"), Err(InvalidNotation));
+}
+
+#[test]
+fn test_from_str() {
+    let game: TicTacToe = "XO..X...O:X".parse().unwrap();
+
+    assert_eq!(game.get_status(), Running(Player::X));
+    assert_eq!(game.get_place(Place::UpperLeft), Some(Player::X));
+    assert_eq!(game.get_place(Place::Upper), Some(Player::O));
+    assert_eq!(game.get_place(Place::Center), Some(Player::X));
+    assert_eq!(game.get_place(Place::LowerRight), Some(Player::O));
+    assert_eq!(game.possible_moves().len(), 5);
+
+    let draw: TicTacToe = "XOXXOOOXX:-".parse().unwrap();
+    assert_eq!(draw.get_status(), Finished(Draw));
+
+    let win: TicTacToe = "OOOXX.X..:-".parse().unwrap();
+    assert_eq!(win.get_status(), Finished(Win(Player::O)));
+
+    let double: TicTacToe = "XXXOXOXOO:-".parse().unwrap();
+    assert_eq!(double.get_status(), Finished(Win(Player::X)));
+
+    for (game, _minimax) in SolvedTable::global().positions() {
+        assert_eq!(game.to_compact_string().parse().as_ref(), Ok(game));
+    }
+
+    assert_eq!(draw.to_compact_string(), "XOXXOOOXX:-");
+    assert_eq!(win.to_compact_string().parse(), Ok(win));
+
+    let errors = [
+        (".........", ParseError::MissingSide),
+        (".........:", ParseError::InvalidSide),
+        (".........:Y", ParseError::InvalidSide),
+        ("........:X", ParseError::InvalidLength(8)),
+        ("..........:X", ParseError::InvalidLength(10)),
+        ("....-....:X", ParseError::InvalidCharacter('-')),
+        ("XX.......:O", ParseError::InvalidCount(2, 0)),
+        ("O........:X", ParseError::InvalidCount(0, 1)),
+        ("XXXOOO...:-", ParseError::BothPlayersWon),
+        ("XXXOO.O..:-", ParseError::InvalidWinner(Player::X)),
+        ("OOOXX.XX.:X", ParseError::InvalidWinner(Player::O)),
+        ("X........:X", ParseError::WrongSide(Running(Player::O))),
+        ("X........:-", ParseError::WrongSide(Running(Player::O))),
+        (
+            "XXXOO....:O",
+            ParseError::WrongSide(Finished(Win(Player::X))),
+        ),
+    ];
+
+    for &(input, error) in errors.iter() {
+        assert_eq!(input.parse::<TicTacToe>(), Err(error));
+    }
+}
+
+#[test]
+fn test_conformance() {
+    let report = check_conformance(&TicTacToe::new(), 200, 9, 0x7AC7).unwrap();
+
+    assert_eq!(report.games, 200);
+    assert!(report.moves >= 200 * 5 && report.moves <= 200 * 9);
+    assert_eq!(report.states, report.moves + report.games);
+
+    let game: TicTacToe = "XO..X...O:X".parse().unwrap();
+    let report = check_conformance(&game, 50, 5, 1).unwrap();
+
+    assert_eq!(report.games, 50);
+
+    let finished: TicTacToe = "XXXOO....:-".parse().unwrap();
+    let report = check_conformance(&finished, 10, 0, 1).unwrap();
+
+    assert_eq!(report.moves, 0);
+    assert_eq!(report.states, 10);
+
+    assert!(matches!(
+        check_conformance(&TicTacToe::new(), 1, 8, 1),
+        Err(Violation::GameTooLong(_))
+    ));
+}
+
+#[test]
+fn test_perft() {
+    let game = TicTacToe::new();
+
+    let counts: Vec<(usize, usize)> = (0..=9)
+        .map(|depth| perft(&game, depth).unwrap())
+        .map(|count| (count.leaves, count.finished))
+        .collect();
+
+    assert_eq!(
+        counts,
+        vec![
+            (1, 0),
+            (9, 0),
+            (72, 0),
+            (504, 0),
+            (3024, 0),
+            (15120, 1440),
+            (56160, 6768),
+            (154944, 54720),
+            (255168, 127296),
+            (255168, 255168),
+        ]
+    );
+
+    let moves = divide(&game, 9).unwrap();
+
+    assert_eq!(moves.len(), 9);
+
+    for (place, count) in moves.iter() {
+        let expected = match place {
+            Place::Center => 25872,
+            Place::UpperLeft | Place::UpperRight | Place::LowerLeft | Place::LowerRight => 27732,
+            _ => 29592,
+        };
+
+        assert_eq!(count.leaves, expected);
+        assert_eq!(count.finished, expected);
+    }
+
+    assert_eq!(divide(&game, 0), Ok(Vec::new()));
+
+    let finished: TicTacToe = "XXXOO....:-".parse().unwrap();
+
+    assert_eq!(
+        perft(&finished, 3),
+        Ok(PerftCount {
+            leaves: 1,
+            finished: 1
+        })
+    );
+    assert_eq!(divide(&finished, 3), Ok(Vec::new()));
+}
@@ -0,0 +1,195 @@
+use crate::*;
+
+const BOARD_WIN_VALUE: isize = 10;
+const THREAT_VALUE: isize = 1;
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct UltimateMove {
+    pub board: Place,
+    pub place: Place,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct UltimateTicTacToe {
+    status: Status,
+    boards: [[Option<Player>; GRID_SIZE]; GRID_SIZE],
+    results: [Option<GameResult>; GRID_SIZE],
+    next_board: Option<Place>,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum UltimateMoveError {
+    InvalidStatus(Status),
+    WrongPlayer(Player),
+    WrongBoard(Place),
+    BoardAlreadyFinished(Place, GameResult),
+    PlaceAlreadyUsed(UltimateMove, Player),
+}
+
+impl UltimateMove {
+    pub fn new(board: Place, place: Place) -> UltimateMove {
+        UltimateMove { board, place }
+    }
+}
+
+fn get_threat_score(grid: &[Option<Player>; GRID_SIZE]) -> isize {
+    let mut score = 0;
+
+    for triple in TRIPLES.iter() {
+        let places: Vec<Option<Player>> = triple
+            .iter()
+            .map(|&p| grid[TicTacToe::get_grid_index(p)])
+            .collect();
+
+        let count = |player| places.iter().filter(|&&p| p == Some(player)).count();
+
+        score += match (count(Player::X), count(Player::O)) {
+            (2, 0) => THREAT_VALUE,
+            (0, 2) => -THREAT_VALUE,
+            _ => 0,
+        };
+    }
+
+    score
+}
+
+impl UltimateTicTacToe {
+    pub fn new() -> UltimateTicTacToe {
+        UltimateTicTacToe {
+            status: Status::Running(Player::X),
+            boards: [[None; GRID_SIZE]; GRID_SIZE],
+            results: [None; GRID_SIZE],
+            next_board: None,
+        }
+    }
+
+    pub fn get_status(&self) -> Status {
+        self.status
+    }
+
+    pub fn get_next_board(&self) -> Option<Place> {
+        self.next_board
+    }
+
+    pub fn get_board_result(&self, board: Place) -> Option<GameResult> {
+        self.results[TicTacToe::get_grid_index(board)]
+    }
+
+    pub fn get_place(&self, board: Place, place: Place) -> Option<Player> {
+        self.boards[TicTacToe::get_grid_index(board)][TicTacToe::get_grid_index(place)]
+    }
+
+    pub fn possible_moves(&self) -> Vec<UltimateMove> {
+        if let Status::Finished(_) = self.status {
+            return Vec::new();
+        }
+
+        let boards: Vec<usize> = match self.next_board {
+            Some(board) => vec![TicTacToe::get_grid_index(board)],
+            None => (0..GRID_SIZE)
+                .filter(|&i| self.results[i].is_none())
+                .collect(),
+        };
+
+        boards
+            .into_iter()
+            .flat_map(|i| {
+                self.boards[i]
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_j, p)| p.is_none())
+                    .map(move |(j, _p)| {
+                        UltimateMove::new(
+                            TicTacToe::get_grid_place(i),
+                            TicTacToe::get_grid_place(j),
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    fn check_win(&self) -> Option<GameResult> {
+        let mut winners = [None; GRID_SIZE];
+
+        for (winner, result) in winners.iter_mut().zip(self.results.iter()) {
+            *winner = result.and_then(GameResult::winner);
+        }
+
+        let winner = get_winner(&winners);
+
+        let all_finished = self.results.iter().all(|r| r.is_some());
+
+        if winner.is_some() || all_finished {
+            Some(GameResult::from(winner))
+        } else {
+            None
+        }
+    }
+
+    pub fn make_move(
+        &mut self,
+        player: Player,
+        mov: UltimateMove,
+    ) -> Result<(), UltimateMoveError> {
+        let status_player = match self.status {
+            Status::Finished(_) => return Err(UltimateMoveError::InvalidStatus(self.status)),
+            Status::Running(status_player) => status_player,
+        };
+
+        if let Some(result) = self.get_board_result(mov.board) {
+            return Err(UltimateMoveError::BoardAlreadyFinished(mov.board, result));
+        }
+
+        match self.next_board {
+            Some(board) if board != mov.board => {
+                return Err(UltimateMoveError::WrongBoard(board));
+            }
+            _ => (),
+        }
+
+        if let Some(place_player) = self.get_place(mov.board, mov.place) {
+            return Err(UltimateMoveError::PlaceAlreadyUsed(mov, place_player));
+        } else if status_player != player {
+            return Err(UltimateMoveError::WrongPlayer(status_player));
+        }
+
+        let board = TicTacToe::get_grid_index(mov.board);
+
+        self.boards[board][TicTacToe::get_grid_index(mov.place)] = Some(player);
+        self.results[board] = check_grid(&self.boards[board]);
+
+        self.next_board = match self.get_board_result(mov.place) {
+            Some(_) => None,
+            None => Some(mov.place),
+        };
+
+        self.status = if let Some(result) = self.check_win() {
+            Status::Finished(result)
+        } else {
+            Status::Running(player.other())
+        };
+
+        Ok(())
+    }
+
+    pub fn get_board_score(&self) -> isize {
+        let mut score = 0;
+
+        for (grid, result) in self.boards.iter().zip(self.results.iter()) {
+            score += match result {
+                Some(GameResult::Win(Player::X)) => BOARD_WIN_VALUE,
+                Some(GameResult::Win(Player::O)) => -BOARD_WIN_VALUE,
+                Some(GameResult::Draw) => 0,
+                None => get_threat_score(grid),
+            };
+        }
+
+        score
+    }
+}
+
+impl Default for UltimateTicTacToe {
+    fn default() -> UltimateTicTacToe {
+        UltimateTicTacToe::new()
+    }
+}